@@ -0,0 +1,71 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use f1_2021_telemetry::packet::car_telemetry::TelemetryView;
+use f1_2021_telemetry::packet::motion::MotionView;
+use f1_2021_telemetry::packet::{Packet, PacketType};
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::read_sample;
+
+fn motion(c: &mut Criterion) {
+    let raw = read_sample("motion.pkt");
+
+    let mut group = c.benchmark_group("motion player position");
+
+    group.bench_function("eager", |b| {
+        b.iter(|| {
+            let packet = Packet::parse(black_box(&raw)).unwrap();
+
+            match packet.data {
+                PacketType::Motion(data) => {
+                    data.car_motion_data[packet.header.player_car_index as usize].world_positon
+                }
+                _ => unreachable!(),
+            }
+        })
+    });
+
+    group.bench_function("view", |b| {
+        b.iter(|| {
+            let view = MotionView::new(black_box(&raw)).unwrap();
+
+            view.player_car().unwrap().world_position()
+        })
+    });
+
+    group.finish();
+}
+
+fn telemetry(c: &mut Criterion) {
+    let raw = read_sample("car_telemetry.pkt");
+
+    let mut group = c.benchmark_group("telemetry player speed");
+
+    group.bench_function("eager", |b| {
+        b.iter(|| {
+            let packet = Packet::parse(black_box(&raw)).unwrap();
+
+            match packet.data {
+                PacketType::CarTelemetry(data) => {
+                    data.car_telemetry_data[packet.header.player_car_index as usize].speed
+                }
+                _ => unreachable!(),
+            }
+        })
+    });
+
+    group.bench_function("view", |b| {
+        b.iter(|| {
+            let view = TelemetryView::new(black_box(&raw)).unwrap();
+
+            view.player_car().unwrap().speed()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, motion, telemetry);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+use std::net::{IpAddr, SocketAddr};
+use tokio_stream::StreamExt;
+
+use f1_2021_telemetry::pcap::PcapWriter;
+
+/// Writes the game's telemetry to the pcap file given as first argument, to inspect in Wireshark
+#[tokio::main]
+async fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "session.pcap".to_string());
+
+    let ip_address = IpAddr::from([0, 0, 0, 0]);
+    let port = 2021;
+    let socket = SocketAddr::new(ip_address, port);
+
+    let mut stream = f1_2021_telemetry::F1_2021::telemetry_with_source(socket).unwrap();
+    let mut writer = PcapWriter::create(&path).unwrap();
+
+    // The socket listens on every interface, the capture shows the packets sent to localhost
+    let destination = SocketAddr::new(IpAddr::from([127, 0, 0, 1]), port);
+
+    while let Some(packet) = stream.next().await {
+        match packet {
+            Ok(packet) => {
+                writer.write_received(&packet, destination).unwrap();
+                writer.flush().unwrap();
+            }
+            Err(err) => eprintln!("Bad packet: {}", err),
+        }
+    }
+}
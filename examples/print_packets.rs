@@ -1,9 +1,6 @@
 use std::net::{IpAddr, SocketAddr};
 use tokio_stream::StreamExt;
 
-use f1_2021_telemetry;
-use f1_2021_telemetry::packet;
-
 #[tokio::main]
 async fn main() {
     let ip_address = IpAddr::from([0, 0, 0, 0]);
@@ -13,6 +10,9 @@ async fn main() {
     let mut stream = f1_2021_telemetry::F1_2021::telemetry(socket).unwrap();
 
     while let Some(packet) = stream.next().await {
-        println!("{:?}", packet.header);
+        match packet {
+            Ok(packet) => println!("{:?}", packet.header),
+            Err(err) => eprintln!("Bad packet: {}", err),
+        }
     }
 }
@@ -0,0 +1,24 @@
+use std::net::UdpSocket;
+use std::time::SystemTime;
+
+use f1_2021_telemetry::recording::Recorder;
+
+/// Records every datagram sent by the game to the file given as first argument
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "session.f1rec".to_string());
+
+    let socket = UdpSocket::bind("0.0.0.0:2021").unwrap();
+    let mut recorder = Recorder::append(&path).unwrap();
+
+    let mut buf = [0u8; 2048];
+
+    loop {
+        let (len, source) = socket.recv_from(&mut buf).unwrap();
+
+        recorder
+            .record(SystemTime::now(), source, &buf[..len])
+            .unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    net::{SocketAddr, UdpSocket},
+    time::Duration,
+};
+
+use crate::error::F1Error;
+use crate::packet::Packet;
+
+/// Size of the receive buffer, larger than any packet the game sends
+const RECEIVE_BUFFER_SIZE: usize = 2048;
+
+/// Receives and decodes packets from a blocking `std::net::UdpSocket`
+pub struct BlockingTelemetry {
+    socket: UdpSocket,
+    buf: [u8; RECEIVE_BUFFER_SIZE],
+}
+
+impl BlockingTelemetry {
+    pub fn bind(socket_address: SocketAddr) -> Result<Self, F1Error> {
+        let socket = UdpSocket::bind(socket_address)?;
+
+        Ok(BlockingTelemetry {
+            socket,
+            buf: [0; RECEIVE_BUFFER_SIZE],
+        })
+    }
+
+    /// Sets how long to wait for a datagram, `None` waits forever
+    /// When the timeout expires the iterator returns an `F1Error::IoError`
+    /// of kind `WouldBlock` or `TimedOut`, depending on the platform
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<(), F1Error> {
+        Ok(self.socket.set_read_timeout(timeout)?)
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, F1Error> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// Waits for the next datagram and decodes it
+    pub fn recv(&mut self) -> Result<Packet, F1Error> {
+        self.recv_from().map(|(packet, _addr)| packet)
+    }
+
+    /// Same as `recv`, but also returns the address the datagram was sent from
+    pub fn recv_from(&mut self) -> Result<(Packet, SocketAddr), F1Error> {
+        let (len, addr) = self.socket.recv_from(&mut self.buf)?;
+        let packet = Packet::parse(&self.buf[..len])?;
+
+        Ok((packet, addr))
+    }
+}
+
+impl Iterator for BlockingTelemetry {
+    type Item = Result<Packet, F1Error>;
+
+    /// Never returns `None`, errors are returned and receiving continues
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.recv())
+    }
+}
@@ -0,0 +1,73 @@
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{error, packet};
+
+pub struct F1_2021_Decoder;
+
+impl Decoder for F1_2021_Decoder {
+    type Item = packet::Packet;
+
+    type Error = error::F1Error;
+
+    /// This method is called after an UDP datagram is received
+    /// It will try to parse a packet from the data
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let len = src.len();
+
+        if len == 0 {
+            return Ok(None);
+        }
+        // Buffer needs to be advanced, otherwise same frame will be processed
+        // This is done before parsing so a bad datagram is dropped as well
+        let useful_buf = src.split_to(len);
+
+        let packet = packet::Packet::parse(&useful_buf);
+
+        match packet {
+            Ok(pack) => Ok(Some(pack)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Same as `F1_2021_Decoder`, but also keeps the datagram as it was received
+pub(crate) struct RawDatagramDecoder;
+
+impl Decoder for RawDatagramDecoder {
+    type Item = (packet::Packet, Vec<u8>);
+
+    type Error = error::F1Error;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let len = src.len();
+
+        if len == 0 {
+            return Ok(None);
+        }
+
+        let useful_buf = src.split_to(len);
+
+        let packet = packet::Packet::parse(&useful_buf)?;
+
+        Ok(Some((packet, useful_buf.to_vec())))
+    }
+}
+
+pub struct F1_2021_Encoder;
+
+impl Encoder<packet::Packet> for F1_2021_Encoder {
+    type Error = error::F1Error;
+
+    /// Writes the packet as a single UDP datagram
+    fn encode(
+        &mut self,
+        item: packet::Packet,
+        dst: &mut bytes::BytesMut,
+    ) -> Result<(), Self::Error> {
+        dst.reserve(packet::MAX_PACKET_SIZE);
+
+        packet::write_packet(&item, dst);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "std")]
 use std::io;
 
 use thiserror::Error;
@@ -8,8 +9,15 @@ pub enum F1Error {
     ConversionError,
     #[error("Not enough data to parse")]
     IncompleteData,
+    #[cfg(feature = "std")]
     #[error("IO error")]
     IoError(#[from] io::Error),
     #[error("Can't convert byte array to string")]
-    UTF8Error(#[from] std::str::Utf8Error),
+    UTF8Error(#[from] core::str::Utf8Error),
+    #[cfg(feature = "std")]
+    #[error("Not a recording or unsupported recording version")]
+    InvalidRecording,
+    #[cfg(feature = "std")]
+    #[error("Not a pcap or pcapng capture")]
+    InvalidCapture,
 }
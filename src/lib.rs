@@ -1,54 +1,123 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(non_camel_case_types)]
-use std::{io::Cursor, net::SocketAddr};
 
+//! Decoder for the F1 2021 UDP telemetry packets
+//!
+//! The `packet`, `snapshot`, `utils` and `error` modules only need `alloc` and build with
+//! `default-features = false` for `no_std` targets.
+//! The `std` feature adds the blocking UDP receiver, recordings, replay and pcap captures,
+//! the `tokio` feature the async receiver.
+//!
+//! The `serde` feature derives `Serialize` and `Deserialize` for every packet type.
+//! Fields keep their Rust names, except `CarMotionData::world_positon` serialized as
+//! `world_position`, and enums their variant names, `PacketType` is externally
+//! tagged, `EventDataDetails` is tagged with a `type` field and `ButtonSet` is its raw `u32`.
+
+extern crate alloc;
+
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+#[cfg(feature = "std")]
+use std::{net::SocketAddr, time::Instant};
+
+#[cfg(feature = "tokio")]
 use tokio_stream::{Stream, StreamExt};
-use tokio_util::{codec::Decoder, udp::UdpFramed};
+#[cfg(feature = "tokio")]
+use tokio_util::udp::UdpFramed;
 
+#[cfg(feature = "std")]
+pub mod blocking;
+#[cfg(feature = "tokio")]
+mod codec;
 pub mod error;
 pub mod packet;
+#[cfg(feature = "std")]
+pub mod pcap;
+#[cfg(feature = "std")]
+pub mod recording;
+#[cfg(feature = "std")]
+pub mod replay;
+pub mod snapshot;
 pub mod utils;
 
+#[cfg(feature = "tokio")]
+pub use codec::{F1_2021_Decoder, F1_2021_Encoder};
+
+#[cfg(feature = "std")]
 pub struct F1_2021;
 
+#[cfg(feature = "std")]
 impl F1_2021 {
+    /// Creates a blocking Iterator of decoded packets, for use without an async runtime
+    /// A datagram that can't be decoded is returned as an error,
+    /// the iterator keeps receiving after it
+    pub fn blocking_telemetry(
+        socket_address: SocketAddr,
+    ) -> Result<blocking::BlockingTelemetry, error::F1Error> {
+        blocking::BlockingTelemetry::bind(socket_address)
+    }
+
     /// Creates an async Stream of decoded packets
+    /// A datagram that can't be decoded is returned as an error,
+    /// the stream keeps receiving after it
+    #[cfg(feature = "tokio")]
     pub fn telemetry(
         socket_address: SocketAddr,
-    ) -> Result<impl Stream<Item = packet::Packet>, error::F1Error> {
-        let socket = std::net::UdpSocket::bind(&socket_address)?;
-        socket.set_nonblocking(true)?;
-        let socket = tokio::net::UdpSocket::from_std(socket)?;
-
-        Ok(UdpFramed::new(socket, F1_2021_Decoder)
-            .map(|result| result.unwrap())
-            .map(|(packet, _addr)| packet))
+    ) -> Result<impl Stream<Item = Result<packet::Packet, error::F1Error>>, error::F1Error> {
+        Ok(Self::framed(socket_address, F1_2021_Decoder)?
+            .map(|result| result.map(|(packet, _addr)| packet)))
     }
-}
 
-pub struct F1_2021_Decoder;
-
-impl Decoder for F1_2021_Decoder {
-    type Item = packet::Packet;
-
-    type Error = error::F1Error;
-
-    /// This method is called after an UDP datagram is received
-    /// It will try to parse a packet from the data
-    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        let len = src.len();
+    /// Same as `telemetry`, but every packet also carries the address
+    /// it was sent from and the time it was received
+    #[cfg(feature = "tokio")]
+    pub fn telemetry_with_source(
+        socket_address: SocketAddr,
+    ) -> Result<impl Stream<Item = Result<ReceivedPacket, error::F1Error>>, error::F1Error> {
+        Ok(
+            Self::framed(socket_address, codec::RawDatagramDecoder)?.map(|result| {
+                result.map(|((packet, datagram), source)| ReceivedPacket {
+                    packet,
+                    datagram,
+                    source,
+                    received_at: Instant::now(),
+                })
+            }),
+        )
+    }
 
-        if len < packet::header::HEADER_SIZE {
-            return Ok(None);
-        }
-        // Buffer needs to be advanced, otherwise same frame will be processed
-        let mut useful_buf = src.split_to(len);
-        let mut cursor = Cursor::new(&mut useful_buf);
+    /// Creates an async Stream of packets grouped by frame,
+    /// a partial frame is emitted after `timeout`
+    #[cfg(feature = "tokio")]
+    pub fn frame_snapshots(
+        socket_address: SocketAddr,
+        timeout: Duration,
+    ) -> Result<impl Stream<Item = Result<snapshot::FrameSnapshot, error::F1Error>>, error::F1Error>
+    {
+        Ok(snapshot::FrameSnapshots::new(
+            Box::pin(Self::telemetry(socket_address)?),
+            timeout,
+        ))
+    }
 
-        let packet = packet::parse_packet(&mut cursor);
+    #[cfg(feature = "tokio")]
+    fn framed<D>(socket_address: SocketAddr, decoder: D) -> Result<UdpFramed<D>, error::F1Error> {
+        let socket = std::net::UdpSocket::bind(socket_address)?;
+        socket.set_nonblocking(true)?;
+        let socket = tokio::net::UdpSocket::from_std(socket)?;
 
-        match packet {
-            Ok(pack) => Ok(Some(pack)),
-            Err(err) => Err(err),
-        }
+        Ok(UdpFramed::new(socket, decoder))
     }
 }
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceivedPacket {
+    pub packet: packet::Packet,
+    /// The datagram as it was received, `packet` decodes it
+    pub datagram: Vec<u8>,
+    /// Address of the game instance that sent the packet
+    pub source: SocketAddr,
+    /// Monotonic time at which the packet was decoded
+    pub received_at: Instant,
+}
@@ -1,42 +1,128 @@
 #![allow(clippy::large_enum_variant)]
-use bytes::BytesMut;
+use bytes::{Buf, Bytes, BytesMut};
 
 use crate::error::F1Error;
-use std::io::Cursor;
 
+pub mod appendix;
+pub mod car_damage;
+pub mod car_setups;
+pub mod car_status;
 pub mod car_telemetry;
 pub mod event;
+pub mod final_classification;
 pub mod header;
+pub mod lap_data;
+pub mod lobby_info;
 pub mod motion;
+pub mod participants;
+pub mod session;
+pub mod session_history;
 
+use self::car_damage::*;
+use self::car_setups::*;
+use self::car_status::*;
 use self::car_telemetry::*;
 use self::event::*;
+use self::final_classification::*;
 use self::header::*;
+use self::lap_data::*;
+use self::lobby_info::*;
 use self::motion::*;
+use self::participants::*;
+use self::session::*;
+use self::session_history::*;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PacketType {
     Motion(MotionData),
+    Session(SessionData),
+    LapData(LapData),
     Event(EventData),
+    Participants(ParticipantsData),
+    CarSetups(CarSetupsData),
     CarTelemetry(TelemetryData),
+    CarStatus(StatusData),
+    FinalClassification(FinalClassificationData),
+    LobbyInfo(LobbyData),
+    CarDamage(DamageData),
+    SessionHistory(SessionHistoryData),
+    /// Kept so existing matches still compile
+    #[deprecated(note = "every packet id is decoded, parse_packet never returns it")]
     Unimplemented,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Packet {
     pub header: Header,
     pub data: PacketType,
 }
 
-pub fn parse_packet(buf: &mut Cursor<&mut BytesMut>) -> Result<Packet, F1Error> {
+impl Packet {
+    /// Decodes a packet from a datagram in the game's wire format
+    /// Bytes after the end of the packet are ignored
+    pub fn parse(bytes: &[u8]) -> Result<Packet, F1Error> {
+        let mut buf = bytes;
+
+        parse_packet(&mut buf)
+    }
+
+    /// Serializes the packet into a datagram in the game's wire format
+    pub fn to_bytes(&self) -> Bytes {
+        let mut buf = BytesMut::with_capacity(MAX_PACKET_SIZE);
+
+        write_packet(self, &mut buf);
+
+        buf.freeze()
+    }
+}
+
+/// Size of the largest packet type, Motion
+pub const MAX_PACKET_SIZE: usize = HEADER_SIZE + MOTION_PACKET_SIZE;
+
+pub fn parse_packet(buf: &mut impl Buf) -> Result<Packet, F1Error> {
     let header = header::parse_header(buf)?;
 
     let data = match header.packet_id {
         PacketId::Motion => PacketType::Motion(parse_motion_packet(buf)?),
+        PacketId::Session => PacketType::Session(parse_session_packet(buf)?),
+        PacketId::LapData => PacketType::LapData(parse_lap_data_packet(buf)?),
         PacketId::Event => PacketType::Event(parse_event_packet(buf)?),
+        PacketId::Participants => PacketType::Participants(parse_participants_packet(buf)?),
+        PacketId::CarSetups => PacketType::CarSetups(parse_car_setups_packet(buf)?),
         PacketId::CarTelemetry => PacketType::CarTelemetry(parse_car_telemetry_packet(buf)?),
-        _ => PacketType::Unimplemented,
+        PacketId::CarStatus => PacketType::CarStatus(parse_car_status_packet(buf)?),
+        PacketId::FinalClassification => {
+            PacketType::FinalClassification(parse_final_classification_packet(buf)?)
+        }
+        PacketId::LobbyInfo => PacketType::LobbyInfo(parse_lobby_info_packet(buf)?),
+        PacketId::CarDamage => PacketType::CarDamage(parse_car_damage_packet(buf)?),
+        PacketId::SessionHistory => PacketType::SessionHistory(parse_session_history_packet(buf)?),
     };
 
     Ok(Packet { header, data })
 }
+
+/// Appends the packet to the buffer in the game's wire format
+/// The header is written as is, its packet id should match the data
+#[allow(deprecated)]
+pub fn write_packet(packet: &Packet, buf: &mut BytesMut) {
+    write_header(&packet.header, buf);
+
+    match &packet.data {
+        PacketType::Motion(data) => write_motion_packet(data, buf),
+        PacketType::Session(data) => write_session_packet(data, buf),
+        PacketType::LapData(data) => write_lap_data_packet(data, buf),
+        PacketType::Event(data) => write_event_packet(data, buf),
+        PacketType::Participants(data) => write_participants_packet(data, buf),
+        PacketType::CarSetups(data) => write_car_setups_packet(data, buf),
+        PacketType::CarTelemetry(data) => write_car_telemetry_packet(data, buf),
+        PacketType::CarStatus(data) => write_car_status_packet(data, buf),
+        PacketType::FinalClassification(data) => write_final_classification_packet(data, buf),
+        PacketType::LobbyInfo(data) => write_lobby_info_packet(data, buf),
+        PacketType::CarDamage(data) => write_car_damage_packet(data, buf),
+        PacketType::SessionHistory(data) => write_session_history_packet(data, buf),
+        PacketType::Unimplemented => {}
+    }
+}
@@ -0,0 +1,1518 @@
+//! Names for the ids used in the packets, as listed in the appendices of the game's UDP specification
+//! Every enum converts from and to the raw `u8` and keeps values it doesn't know in `Unknown`
+//! The `short_code`s are abbreviations defined by this crate, the game doesn't send or list any.
+//! `Driver` has none, three letters can't tell all the drivers of the appendix apart
+
+use core::fmt;
+
+/// Team ids, used in Participants and Lobby Info packets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Team {
+    Mercedes,
+    Ferrari,
+    RedBullRacing,
+    Williams,
+    AstonMartin,
+    Alpine,
+    AlphaTauri,
+    Haas,
+    McLaren,
+    AlfaRomeo,
+    ArtGp19,
+    Campos19,
+    Carlin19,
+    SauberJuniorCharouz19,
+    Dams19,
+    UniVirtuosi19,
+    MpMotorsport19,
+    Prema19,
+    Trident19,
+    Arden19,
+    ArtGp20,
+    Campos20,
+    Carlin20,
+    Charouz20,
+    Dams20,
+    UniVirtuosi20,
+    MpMotorsport20,
+    Prema20,
+    Trident20,
+    Bwt20,
+    Hitech20,
+    Mercedes2020,
+    Ferrari2020,
+    RedBull2020,
+    Williams2020,
+    RacingPoint2020,
+    Renault2020,
+    AlphaTauri2020,
+    Haas2020,
+    McLaren2020,
+    AlfaRomeo2020,
+    AstonMartinDb11V12,
+    AstonMartinVantageF1Edition,
+    AstonMartinVantageSafetyCar,
+    FerrariF8Tributo,
+    FerrariRoma,
+    McLaren720S,
+    McLarenArtura,
+    MercedesAmgGtBlackSeriesSafetyCar,
+    MercedesAmgGtrPro,
+    F1CustomTeam,
+    Prema21,
+    UniVirtuosi21,
+    Carlin21,
+    Hitech21,
+    ArtGp21,
+    MpMotorsport21,
+    Charouz21,
+    Dams21,
+    Campos21,
+    Bwt21,
+    Trident21,
+    MercedesAmgGtBlackSeries,
+    /// Value not listed in the game's appendix
+    Unknown(u8),
+}
+
+impl Team {
+    /// Three letter abbreviation of the team or manufacturer name, defined by this crate
+    /// Not unique, the seasons of a team and the cars of a manufacturer share it
+    pub fn short_code(&self) -> Option<&'static str> {
+        match self {
+            Team::Mercedes => Some("MER"),
+            Team::Ferrari => Some("FER"),
+            Team::RedBullRacing => Some("RBR"),
+            Team::Williams => Some("WIL"),
+            Team::AstonMartin => Some("AMR"),
+            Team::Alpine => Some("ALP"),
+            Team::AlphaTauri => Some("APT"),
+            Team::Haas => Some("HAA"),
+            Team::McLaren => Some("MCL"),
+            Team::AlfaRomeo => Some("ALF"),
+            Team::ArtGp19 => Some("ART"),
+            Team::Campos19 => Some("CAM"),
+            Team::Carlin19 => Some("CAR"),
+            Team::SauberJuniorCharouz19 => Some("CHA"),
+            Team::Dams19 => Some("DAM"),
+            Team::UniVirtuosi19 => Some("UNI"),
+            Team::MpMotorsport19 => Some("MPM"),
+            Team::Prema19 => Some("PRE"),
+            Team::Trident19 => Some("TRI"),
+            Team::Arden19 => Some("ARD"),
+            Team::ArtGp20 => Some("ART"),
+            Team::Campos20 => Some("CAM"),
+            Team::Carlin20 => Some("CAR"),
+            Team::Charouz20 => Some("CHA"),
+            Team::Dams20 => Some("DAM"),
+            Team::UniVirtuosi20 => Some("UNI"),
+            Team::MpMotorsport20 => Some("MPM"),
+            Team::Prema20 => Some("PRE"),
+            Team::Trident20 => Some("TRI"),
+            Team::Bwt20 => Some("BWT"),
+            Team::Hitech20 => Some("HIT"),
+            Team::Mercedes2020 => Some("MER"),
+            Team::Ferrari2020 => Some("FER"),
+            Team::RedBull2020 => Some("RBR"),
+            Team::Williams2020 => Some("WIL"),
+            Team::RacingPoint2020 => Some("RPT"),
+            Team::Renault2020 => Some("REN"),
+            Team::AlphaTauri2020 => Some("APT"),
+            Team::Haas2020 => Some("HAA"),
+            Team::McLaren2020 => Some("MCL"),
+            Team::AlfaRomeo2020 => Some("ALF"),
+            Team::AstonMartinDb11V12 => Some("AMR"),
+            Team::AstonMartinVantageF1Edition => Some("AMR"),
+            Team::AstonMartinVantageSafetyCar => Some("AMR"),
+            Team::FerrariF8Tributo => Some("FER"),
+            Team::FerrariRoma => Some("FER"),
+            Team::McLaren720S => Some("MCL"),
+            Team::McLarenArtura => Some("MCL"),
+            Team::MercedesAmgGtBlackSeriesSafetyCar => Some("MER"),
+            Team::MercedesAmgGtrPro => Some("MER"),
+            Team::F1CustomTeam => Some("CUS"),
+            Team::Prema21 => Some("PRE"),
+            Team::UniVirtuosi21 => Some("UNI"),
+            Team::Carlin21 => Some("CAR"),
+            Team::Hitech21 => Some("HIT"),
+            Team::ArtGp21 => Some("ART"),
+            Team::MpMotorsport21 => Some("MPM"),
+            Team::Charouz21 => Some("CHA"),
+            Team::Dams21 => Some("DAM"),
+            Team::Campos21 => Some("CAM"),
+            Team::Bwt21 => Some("BWT"),
+            Team::Trident21 => Some("TRI"),
+            Team::MercedesAmgGtBlackSeries => Some("MER"),
+            Team::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<u8> for Team {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Team::Mercedes,
+            1 => Team::Ferrari,
+            2 => Team::RedBullRacing,
+            3 => Team::Williams,
+            4 => Team::AstonMartin,
+            5 => Team::Alpine,
+            6 => Team::AlphaTauri,
+            7 => Team::Haas,
+            8 => Team::McLaren,
+            9 => Team::AlfaRomeo,
+            42 => Team::ArtGp19,
+            43 => Team::Campos19,
+            44 => Team::Carlin19,
+            45 => Team::SauberJuniorCharouz19,
+            46 => Team::Dams19,
+            47 => Team::UniVirtuosi19,
+            48 => Team::MpMotorsport19,
+            49 => Team::Prema19,
+            50 => Team::Trident19,
+            51 => Team::Arden19,
+            70 => Team::ArtGp20,
+            71 => Team::Campos20,
+            72 => Team::Carlin20,
+            73 => Team::Charouz20,
+            74 => Team::Dams20,
+            75 => Team::UniVirtuosi20,
+            76 => Team::MpMotorsport20,
+            77 => Team::Prema20,
+            78 => Team::Trident20,
+            79 => Team::Bwt20,
+            80 => Team::Hitech20,
+            85 => Team::Mercedes2020,
+            86 => Team::Ferrari2020,
+            87 => Team::RedBull2020,
+            88 => Team::Williams2020,
+            89 => Team::RacingPoint2020,
+            90 => Team::Renault2020,
+            91 => Team::AlphaTauri2020,
+            92 => Team::Haas2020,
+            93 => Team::McLaren2020,
+            94 => Team::AlfaRomeo2020,
+            95 => Team::AstonMartinDb11V12,
+            96 => Team::AstonMartinVantageF1Edition,
+            97 => Team::AstonMartinVantageSafetyCar,
+            98 => Team::FerrariF8Tributo,
+            99 => Team::FerrariRoma,
+            100 => Team::McLaren720S,
+            101 => Team::McLarenArtura,
+            102 => Team::MercedesAmgGtBlackSeriesSafetyCar,
+            103 => Team::MercedesAmgGtrPro,
+            104 => Team::F1CustomTeam,
+            106 => Team::Prema21,
+            107 => Team::UniVirtuosi21,
+            108 => Team::Carlin21,
+            109 => Team::Hitech21,
+            110 => Team::ArtGp21,
+            111 => Team::MpMotorsport21,
+            112 => Team::Charouz21,
+            113 => Team::Dams21,
+            114 => Team::Campos21,
+            115 => Team::Bwt21,
+            116 => Team::Trident21,
+            117 => Team::MercedesAmgGtBlackSeries,
+            value => Team::Unknown(value),
+        }
+    }
+}
+
+impl From<Team> for u8 {
+    fn from(value: Team) -> Self {
+        match value {
+            Team::Mercedes => 0,
+            Team::Ferrari => 1,
+            Team::RedBullRacing => 2,
+            Team::Williams => 3,
+            Team::AstonMartin => 4,
+            Team::Alpine => 5,
+            Team::AlphaTauri => 6,
+            Team::Haas => 7,
+            Team::McLaren => 8,
+            Team::AlfaRomeo => 9,
+            Team::ArtGp19 => 42,
+            Team::Campos19 => 43,
+            Team::Carlin19 => 44,
+            Team::SauberJuniorCharouz19 => 45,
+            Team::Dams19 => 46,
+            Team::UniVirtuosi19 => 47,
+            Team::MpMotorsport19 => 48,
+            Team::Prema19 => 49,
+            Team::Trident19 => 50,
+            Team::Arden19 => 51,
+            Team::ArtGp20 => 70,
+            Team::Campos20 => 71,
+            Team::Carlin20 => 72,
+            Team::Charouz20 => 73,
+            Team::Dams20 => 74,
+            Team::UniVirtuosi20 => 75,
+            Team::MpMotorsport20 => 76,
+            Team::Prema20 => 77,
+            Team::Trident20 => 78,
+            Team::Bwt20 => 79,
+            Team::Hitech20 => 80,
+            Team::Mercedes2020 => 85,
+            Team::Ferrari2020 => 86,
+            Team::RedBull2020 => 87,
+            Team::Williams2020 => 88,
+            Team::RacingPoint2020 => 89,
+            Team::Renault2020 => 90,
+            Team::AlphaTauri2020 => 91,
+            Team::Haas2020 => 92,
+            Team::McLaren2020 => 93,
+            Team::AlfaRomeo2020 => 94,
+            Team::AstonMartinDb11V12 => 95,
+            Team::AstonMartinVantageF1Edition => 96,
+            Team::AstonMartinVantageSafetyCar => 97,
+            Team::FerrariF8Tributo => 98,
+            Team::FerrariRoma => 99,
+            Team::McLaren720S => 100,
+            Team::McLarenArtura => 101,
+            Team::MercedesAmgGtBlackSeriesSafetyCar => 102,
+            Team::MercedesAmgGtrPro => 103,
+            Team::F1CustomTeam => 104,
+            Team::Prema21 => 106,
+            Team::UniVirtuosi21 => 107,
+            Team::Carlin21 => 108,
+            Team::Hitech21 => 109,
+            Team::ArtGp21 => 110,
+            Team::MpMotorsport21 => 111,
+            Team::Charouz21 => 112,
+            Team::Dams21 => 113,
+            Team::Campos21 => 114,
+            Team::Bwt21 => 115,
+            Team::Trident21 => 116,
+            Team::MercedesAmgGtBlackSeries => 117,
+            Team::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Team {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Team::Mercedes => f.write_str("Mercedes"),
+            Team::Ferrari => f.write_str("Ferrari"),
+            Team::RedBullRacing => f.write_str("Red Bull Racing"),
+            Team::Williams => f.write_str("Williams"),
+            Team::AstonMartin => f.write_str("Aston Martin"),
+            Team::Alpine => f.write_str("Alpine"),
+            Team::AlphaTauri => f.write_str("Alpha Tauri"),
+            Team::Haas => f.write_str("Haas"),
+            Team::McLaren => f.write_str("McLaren"),
+            Team::AlfaRomeo => f.write_str("Alfa Romeo"),
+            Team::ArtGp19 => f.write_str("Art GP '19"),
+            Team::Campos19 => f.write_str("Campos '19"),
+            Team::Carlin19 => f.write_str("Carlin '19"),
+            Team::SauberJuniorCharouz19 => f.write_str("Sauber Junior Charouz '19"),
+            Team::Dams19 => f.write_str("Dams '19"),
+            Team::UniVirtuosi19 => f.write_str("Uni-Virtuosi '19"),
+            Team::MpMotorsport19 => f.write_str("MP Motorsport '19"),
+            Team::Prema19 => f.write_str("Prema '19"),
+            Team::Trident19 => f.write_str("Trident '19"),
+            Team::Arden19 => f.write_str("Arden '19"),
+            Team::ArtGp20 => f.write_str("Art GP '20"),
+            Team::Campos20 => f.write_str("Campos '20"),
+            Team::Carlin20 => f.write_str("Carlin '20"),
+            Team::Charouz20 => f.write_str("Charouz '20"),
+            Team::Dams20 => f.write_str("Dams '20"),
+            Team::UniVirtuosi20 => f.write_str("Uni-Virtuosi '20"),
+            Team::MpMotorsport20 => f.write_str("MP Motorsport '20"),
+            Team::Prema20 => f.write_str("Prema '20"),
+            Team::Trident20 => f.write_str("Trident '20"),
+            Team::Bwt20 => f.write_str("BWT '20"),
+            Team::Hitech20 => f.write_str("Hitech '20"),
+            Team::Mercedes2020 => f.write_str("Mercedes 2020"),
+            Team::Ferrari2020 => f.write_str("Ferrari 2020"),
+            Team::RedBull2020 => f.write_str("Red Bull 2020"),
+            Team::Williams2020 => f.write_str("Williams 2020"),
+            Team::RacingPoint2020 => f.write_str("Racing Point 2020"),
+            Team::Renault2020 => f.write_str("Renault 2020"),
+            Team::AlphaTauri2020 => f.write_str("Alpha Tauri 2020"),
+            Team::Haas2020 => f.write_str("Haas 2020"),
+            Team::McLaren2020 => f.write_str("McLaren 2020"),
+            Team::AlfaRomeo2020 => f.write_str("Alfa Romeo 2020"),
+            Team::AstonMartinDb11V12 => f.write_str("Aston Martin DB11 V12"),
+            Team::AstonMartinVantageF1Edition => f.write_str("Aston Martin Vantage F1 Edition"),
+            Team::AstonMartinVantageSafetyCar => f.write_str("Aston Martin Vantage Safety Car"),
+            Team::FerrariF8Tributo => f.write_str("Ferrari F8 Tributo"),
+            Team::FerrariRoma => f.write_str("Ferrari Roma"),
+            Team::McLaren720S => f.write_str("McLaren 720S"),
+            Team::McLarenArtura => f.write_str("McLaren Artura"),
+            Team::MercedesAmgGtBlackSeriesSafetyCar => {
+                f.write_str("Mercedes AMG GT Black Series Safety Car")
+            }
+            Team::MercedesAmgGtrPro => f.write_str("Mercedes AMG GTR Pro"),
+            Team::F1CustomTeam => f.write_str("F1 Custom Team"),
+            Team::Prema21 => f.write_str("Prema '21"),
+            Team::UniVirtuosi21 => f.write_str("Uni-Virtuosi '21"),
+            Team::Carlin21 => f.write_str("Carlin '21"),
+            Team::Hitech21 => f.write_str("Hitech '21"),
+            Team::ArtGp21 => f.write_str("Art GP '21"),
+            Team::MpMotorsport21 => f.write_str("MP Motorsport '21"),
+            Team::Charouz21 => f.write_str("Charouz '21"),
+            Team::Dams21 => f.write_str("Dams '21"),
+            Team::Campos21 => f.write_str("Campos '21"),
+            Team::Bwt21 => f.write_str("BWT '21"),
+            Team::Trident21 => f.write_str("Trident '21"),
+            Team::MercedesAmgGtBlackSeries => f.write_str("Mercedes AMG GT Black Series"),
+            Team::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+/// Driver ids, used in Participants packets
+/// Human players in online sessions are sent as 255
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Driver {
+    CarlosSainz,
+    DaniilKvyat,
+    DanielRicciardo,
+    FernandoAlonso,
+    FelipeMassa,
+    KimiRaikkonen,
+    LewisHamilton,
+    MaxVerstappen,
+    NicoHulkenberg,
+    KevinMagnussen,
+    RomainGrosjean,
+    SebastianVettel,
+    SergioPerez,
+    ValtteriBottas,
+    EstebanOcon,
+    LanceStroll,
+    ArronBarnes,
+    MartinGiles,
+    AlexMurray,
+    LucasRoth,
+    IgorCorreia,
+    SophieLevasseur,
+    JonasSchiffer,
+    AlainForest,
+    JayLetourneau,
+    EstoSaari,
+    YasarAtiyeh,
+    CallistoCalabresi,
+    NaotaIzum,
+    HowardClarke,
+    WilheimKaufmann,
+    MarieLaursen,
+    FlavioNieves,
+    PeterBelousov,
+    KlimekMichalski,
+    SantiagoMoreno,
+    BenjaminCoppens,
+    NoahVisser,
+    GertWaldmuller,
+    JulianQuesada,
+    DanielJones,
+    ArtemMarkelov,
+    TadasukeMakino,
+    SeanGelael,
+    NyckDeVries,
+    JackAitken,
+    GeorgeRussell,
+    MaximilianGunther,
+    NireiFukuzumi,
+    LucaGhiotto,
+    LandoNorris,
+    SergioSetteCamara,
+    LouisDeletraz,
+    AntonioFuoco,
+    CharlesLeclerc,
+    PierreGasly,
+    AlexanderAlbon,
+    NicholasLatifi,
+    DorianBoccolacci,
+    NikoKari,
+    RobertoMerhi,
+    ArjunMaini,
+    AlessioLorandi,
+    RubenMeijer,
+    RashidNair,
+    JackTremblay,
+    DevonButler,
+    LukasWeber,
+    AntonioGiovinazzi,
+    RobertKubica,
+    AlainProst,
+    AyrtonSenna,
+    NobuharuMatsushita,
+    NikitaMazepin,
+    GuanyaZhou,
+    MickSchumacher,
+    CallumIlott,
+    JuanManuelCorrea,
+    JordanKing,
+    MahaveerRaghunathan,
+    TatianaCalderon,
+    AnthoineHubert,
+    GuilianoAlesi,
+    RalphBoschung,
+    MichaelSchumacher,
+    DanTicktum,
+    MarcusArmstrong,
+    ChristianLundgaard,
+    YukiTsunoda,
+    JehanDaruvala,
+    GulhermeSamaia,
+    PedroPiquet,
+    FelipeDrugovich,
+    RobertSchwartzman,
+    RoyNissany,
+    MarinoSato,
+    AidanJackson,
+    CasperAkkerman,
+    JensonButton,
+    DavidCoulthard,
+    NicoRosberg,
+    OscarPiastri,
+    LiamLawson,
+    JuriVips,
+    TheoPourchaire,
+    RichardVerschoor,
+    LirimZendeli,
+    DavidBeckmann,
+    AlessioDeledda,
+    BentViscaal,
+    EnzoFittipaldi,
+    MarkWebber,
+    JacquesVilleneuve,
+    /// Value not listed in the game's appendix, or a human player
+    Unknown(u8),
+}
+
+impl From<u8> for Driver {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Driver::CarlosSainz,
+            1 => Driver::DaniilKvyat,
+            2 => Driver::DanielRicciardo,
+            3 => Driver::FernandoAlonso,
+            4 => Driver::FelipeMassa,
+            6 => Driver::KimiRaikkonen,
+            7 => Driver::LewisHamilton,
+            9 => Driver::MaxVerstappen,
+            10 => Driver::NicoHulkenberg,
+            11 => Driver::KevinMagnussen,
+            12 => Driver::RomainGrosjean,
+            13 => Driver::SebastianVettel,
+            14 => Driver::SergioPerez,
+            15 => Driver::ValtteriBottas,
+            17 => Driver::EstebanOcon,
+            19 => Driver::LanceStroll,
+            20 => Driver::ArronBarnes,
+            21 => Driver::MartinGiles,
+            22 => Driver::AlexMurray,
+            23 => Driver::LucasRoth,
+            24 => Driver::IgorCorreia,
+            25 => Driver::SophieLevasseur,
+            26 => Driver::JonasSchiffer,
+            27 => Driver::AlainForest,
+            28 => Driver::JayLetourneau,
+            29 => Driver::EstoSaari,
+            30 => Driver::YasarAtiyeh,
+            31 => Driver::CallistoCalabresi,
+            32 => Driver::NaotaIzum,
+            33 => Driver::HowardClarke,
+            34 => Driver::WilheimKaufmann,
+            35 => Driver::MarieLaursen,
+            36 => Driver::FlavioNieves,
+            37 => Driver::PeterBelousov,
+            38 => Driver::KlimekMichalski,
+            39 => Driver::SantiagoMoreno,
+            40 => Driver::BenjaminCoppens,
+            41 => Driver::NoahVisser,
+            42 => Driver::GertWaldmuller,
+            43 => Driver::JulianQuesada,
+            44 => Driver::DanielJones,
+            45 => Driver::ArtemMarkelov,
+            46 => Driver::TadasukeMakino,
+            47 => Driver::SeanGelael,
+            48 => Driver::NyckDeVries,
+            49 => Driver::JackAitken,
+            50 => Driver::GeorgeRussell,
+            51 => Driver::MaximilianGunther,
+            52 => Driver::NireiFukuzumi,
+            53 => Driver::LucaGhiotto,
+            54 => Driver::LandoNorris,
+            55 => Driver::SergioSetteCamara,
+            56 => Driver::LouisDeletraz,
+            57 => Driver::AntonioFuoco,
+            58 => Driver::CharlesLeclerc,
+            59 => Driver::PierreGasly,
+            62 => Driver::AlexanderAlbon,
+            63 => Driver::NicholasLatifi,
+            64 => Driver::DorianBoccolacci,
+            65 => Driver::NikoKari,
+            66 => Driver::RobertoMerhi,
+            67 => Driver::ArjunMaini,
+            68 => Driver::AlessioLorandi,
+            69 => Driver::RubenMeijer,
+            70 => Driver::RashidNair,
+            71 => Driver::JackTremblay,
+            72 => Driver::DevonButler,
+            73 => Driver::LukasWeber,
+            74 => Driver::AntonioGiovinazzi,
+            75 => Driver::RobertKubica,
+            76 => Driver::AlainProst,
+            77 => Driver::AyrtonSenna,
+            78 => Driver::NobuharuMatsushita,
+            79 => Driver::NikitaMazepin,
+            80 => Driver::GuanyaZhou,
+            81 => Driver::MickSchumacher,
+            82 => Driver::CallumIlott,
+            83 => Driver::JuanManuelCorrea,
+            84 => Driver::JordanKing,
+            85 => Driver::MahaveerRaghunathan,
+            86 => Driver::TatianaCalderon,
+            87 => Driver::AnthoineHubert,
+            88 => Driver::GuilianoAlesi,
+            89 => Driver::RalphBoschung,
+            90 => Driver::MichaelSchumacher,
+            91 => Driver::DanTicktum,
+            92 => Driver::MarcusArmstrong,
+            93 => Driver::ChristianLundgaard,
+            94 => Driver::YukiTsunoda,
+            95 => Driver::JehanDaruvala,
+            96 => Driver::GulhermeSamaia,
+            97 => Driver::PedroPiquet,
+            98 => Driver::FelipeDrugovich,
+            99 => Driver::RobertSchwartzman,
+            100 => Driver::RoyNissany,
+            101 => Driver::MarinoSato,
+            102 => Driver::AidanJackson,
+            103 => Driver::CasperAkkerman,
+            109 => Driver::JensonButton,
+            110 => Driver::DavidCoulthard,
+            111 => Driver::NicoRosberg,
+            112 => Driver::OscarPiastri,
+            113 => Driver::LiamLawson,
+            114 => Driver::JuriVips,
+            115 => Driver::TheoPourchaire,
+            116 => Driver::RichardVerschoor,
+            117 => Driver::LirimZendeli,
+            118 => Driver::DavidBeckmann,
+            121 => Driver::AlessioDeledda,
+            122 => Driver::BentViscaal,
+            123 => Driver::EnzoFittipaldi,
+            125 => Driver::MarkWebber,
+            126 => Driver::JacquesVilleneuve,
+            value => Driver::Unknown(value),
+        }
+    }
+}
+
+impl From<Driver> for u8 {
+    fn from(value: Driver) -> Self {
+        match value {
+            Driver::CarlosSainz => 0,
+            Driver::DaniilKvyat => 1,
+            Driver::DanielRicciardo => 2,
+            Driver::FernandoAlonso => 3,
+            Driver::FelipeMassa => 4,
+            Driver::KimiRaikkonen => 6,
+            Driver::LewisHamilton => 7,
+            Driver::MaxVerstappen => 9,
+            Driver::NicoHulkenberg => 10,
+            Driver::KevinMagnussen => 11,
+            Driver::RomainGrosjean => 12,
+            Driver::SebastianVettel => 13,
+            Driver::SergioPerez => 14,
+            Driver::ValtteriBottas => 15,
+            Driver::EstebanOcon => 17,
+            Driver::LanceStroll => 19,
+            Driver::ArronBarnes => 20,
+            Driver::MartinGiles => 21,
+            Driver::AlexMurray => 22,
+            Driver::LucasRoth => 23,
+            Driver::IgorCorreia => 24,
+            Driver::SophieLevasseur => 25,
+            Driver::JonasSchiffer => 26,
+            Driver::AlainForest => 27,
+            Driver::JayLetourneau => 28,
+            Driver::EstoSaari => 29,
+            Driver::YasarAtiyeh => 30,
+            Driver::CallistoCalabresi => 31,
+            Driver::NaotaIzum => 32,
+            Driver::HowardClarke => 33,
+            Driver::WilheimKaufmann => 34,
+            Driver::MarieLaursen => 35,
+            Driver::FlavioNieves => 36,
+            Driver::PeterBelousov => 37,
+            Driver::KlimekMichalski => 38,
+            Driver::SantiagoMoreno => 39,
+            Driver::BenjaminCoppens => 40,
+            Driver::NoahVisser => 41,
+            Driver::GertWaldmuller => 42,
+            Driver::JulianQuesada => 43,
+            Driver::DanielJones => 44,
+            Driver::ArtemMarkelov => 45,
+            Driver::TadasukeMakino => 46,
+            Driver::SeanGelael => 47,
+            Driver::NyckDeVries => 48,
+            Driver::JackAitken => 49,
+            Driver::GeorgeRussell => 50,
+            Driver::MaximilianGunther => 51,
+            Driver::NireiFukuzumi => 52,
+            Driver::LucaGhiotto => 53,
+            Driver::LandoNorris => 54,
+            Driver::SergioSetteCamara => 55,
+            Driver::LouisDeletraz => 56,
+            Driver::AntonioFuoco => 57,
+            Driver::CharlesLeclerc => 58,
+            Driver::PierreGasly => 59,
+            Driver::AlexanderAlbon => 62,
+            Driver::NicholasLatifi => 63,
+            Driver::DorianBoccolacci => 64,
+            Driver::NikoKari => 65,
+            Driver::RobertoMerhi => 66,
+            Driver::ArjunMaini => 67,
+            Driver::AlessioLorandi => 68,
+            Driver::RubenMeijer => 69,
+            Driver::RashidNair => 70,
+            Driver::JackTremblay => 71,
+            Driver::DevonButler => 72,
+            Driver::LukasWeber => 73,
+            Driver::AntonioGiovinazzi => 74,
+            Driver::RobertKubica => 75,
+            Driver::AlainProst => 76,
+            Driver::AyrtonSenna => 77,
+            Driver::NobuharuMatsushita => 78,
+            Driver::NikitaMazepin => 79,
+            Driver::GuanyaZhou => 80,
+            Driver::MickSchumacher => 81,
+            Driver::CallumIlott => 82,
+            Driver::JuanManuelCorrea => 83,
+            Driver::JordanKing => 84,
+            Driver::MahaveerRaghunathan => 85,
+            Driver::TatianaCalderon => 86,
+            Driver::AnthoineHubert => 87,
+            Driver::GuilianoAlesi => 88,
+            Driver::RalphBoschung => 89,
+            Driver::MichaelSchumacher => 90,
+            Driver::DanTicktum => 91,
+            Driver::MarcusArmstrong => 92,
+            Driver::ChristianLundgaard => 93,
+            Driver::YukiTsunoda => 94,
+            Driver::JehanDaruvala => 95,
+            Driver::GulhermeSamaia => 96,
+            Driver::PedroPiquet => 97,
+            Driver::FelipeDrugovich => 98,
+            Driver::RobertSchwartzman => 99,
+            Driver::RoyNissany => 100,
+            Driver::MarinoSato => 101,
+            Driver::AidanJackson => 102,
+            Driver::CasperAkkerman => 103,
+            Driver::JensonButton => 109,
+            Driver::DavidCoulthard => 110,
+            Driver::NicoRosberg => 111,
+            Driver::OscarPiastri => 112,
+            Driver::LiamLawson => 113,
+            Driver::JuriVips => 114,
+            Driver::TheoPourchaire => 115,
+            Driver::RichardVerschoor => 116,
+            Driver::LirimZendeli => 117,
+            Driver::DavidBeckmann => 118,
+            Driver::AlessioDeledda => 121,
+            Driver::BentViscaal => 122,
+            Driver::EnzoFittipaldi => 123,
+            Driver::MarkWebber => 125,
+            Driver::JacquesVilleneuve => 126,
+            Driver::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Driver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Driver::CarlosSainz => f.write_str("Carlos Sainz"),
+            Driver::DaniilKvyat => f.write_str("Daniil Kvyat"),
+            Driver::DanielRicciardo => f.write_str("Daniel Ricciardo"),
+            Driver::FernandoAlonso => f.write_str("Fernando Alonso"),
+            Driver::FelipeMassa => f.write_str("Felipe Massa"),
+            Driver::KimiRaikkonen => f.write_str("Kimi Räikkönen"),
+            Driver::LewisHamilton => f.write_str("Lewis Hamilton"),
+            Driver::MaxVerstappen => f.write_str("Max Verstappen"),
+            Driver::NicoHulkenberg => f.write_str("Nico Hulkenberg"),
+            Driver::KevinMagnussen => f.write_str("Kevin Magnussen"),
+            Driver::RomainGrosjean => f.write_str("Romain Grosjean"),
+            Driver::SebastianVettel => f.write_str("Sebastian Vettel"),
+            Driver::SergioPerez => f.write_str("Sergio Perez"),
+            Driver::ValtteriBottas => f.write_str("Valtteri Bottas"),
+            Driver::EstebanOcon => f.write_str("Esteban Ocon"),
+            Driver::LanceStroll => f.write_str("Lance Stroll"),
+            Driver::ArronBarnes => f.write_str("Arron Barnes"),
+            Driver::MartinGiles => f.write_str("Martin Giles"),
+            Driver::AlexMurray => f.write_str("Alex Murray"),
+            Driver::LucasRoth => f.write_str("Lucas Roth"),
+            Driver::IgorCorreia => f.write_str("Igor Correia"),
+            Driver::SophieLevasseur => f.write_str("Sophie Levasseur"),
+            Driver::JonasSchiffer => f.write_str("Jonas Schiffer"),
+            Driver::AlainForest => f.write_str("Alain Forest"),
+            Driver::JayLetourneau => f.write_str("Jay Letourneau"),
+            Driver::EstoSaari => f.write_str("Esto Saari"),
+            Driver::YasarAtiyeh => f.write_str("Yasar Atiyeh"),
+            Driver::CallistoCalabresi => f.write_str("Callisto Calabresi"),
+            Driver::NaotaIzum => f.write_str("Naota Izum"),
+            Driver::HowardClarke => f.write_str("Howard Clarke"),
+            Driver::WilheimKaufmann => f.write_str("Wilheim Kaufmann"),
+            Driver::MarieLaursen => f.write_str("Marie Laursen"),
+            Driver::FlavioNieves => f.write_str("Flavio Nieves"),
+            Driver::PeterBelousov => f.write_str("Peter Belousov"),
+            Driver::KlimekMichalski => f.write_str("Klimek Michalski"),
+            Driver::SantiagoMoreno => f.write_str("Santiago Moreno"),
+            Driver::BenjaminCoppens => f.write_str("Benjamin Coppens"),
+            Driver::NoahVisser => f.write_str("Noah Visser"),
+            Driver::GertWaldmuller => f.write_str("Gert Waldmuller"),
+            Driver::JulianQuesada => f.write_str("Julian Quesada"),
+            Driver::DanielJones => f.write_str("Daniel Jones"),
+            Driver::ArtemMarkelov => f.write_str("Artem Markelov"),
+            Driver::TadasukeMakino => f.write_str("Tadasuke Makino"),
+            Driver::SeanGelael => f.write_str("Sean Gelael"),
+            Driver::NyckDeVries => f.write_str("Nyck De Vries"),
+            Driver::JackAitken => f.write_str("Jack Aitken"),
+            Driver::GeorgeRussell => f.write_str("George Russell"),
+            Driver::MaximilianGunther => f.write_str("Maximilian Günther"),
+            Driver::NireiFukuzumi => f.write_str("Nirei Fukuzumi"),
+            Driver::LucaGhiotto => f.write_str("Luca Ghiotto"),
+            Driver::LandoNorris => f.write_str("Lando Norris"),
+            Driver::SergioSetteCamara => f.write_str("Sérgio Sette Câmara"),
+            Driver::LouisDeletraz => f.write_str("Louis Delétraz"),
+            Driver::AntonioFuoco => f.write_str("Antonio Fuoco"),
+            Driver::CharlesLeclerc => f.write_str("Charles Leclerc"),
+            Driver::PierreGasly => f.write_str("Pierre Gasly"),
+            Driver::AlexanderAlbon => f.write_str("Alexander Albon"),
+            Driver::NicholasLatifi => f.write_str("Nicholas Latifi"),
+            Driver::DorianBoccolacci => f.write_str("Dorian Boccolacci"),
+            Driver::NikoKari => f.write_str("Niko Kari"),
+            Driver::RobertoMerhi => f.write_str("Roberto Merhi"),
+            Driver::ArjunMaini => f.write_str("Arjun Maini"),
+            Driver::AlessioLorandi => f.write_str("Alessio Lorandi"),
+            Driver::RubenMeijer => f.write_str("Ruben Meijer"),
+            Driver::RashidNair => f.write_str("Rashid Nair"),
+            Driver::JackTremblay => f.write_str("Jack Tremblay"),
+            Driver::DevonButler => f.write_str("Devon Butler"),
+            Driver::LukasWeber => f.write_str("Lukas Weber"),
+            Driver::AntonioGiovinazzi => f.write_str("Antonio Giovinazzi"),
+            Driver::RobertKubica => f.write_str("Robert Kubica"),
+            Driver::AlainProst => f.write_str("Alain Prost"),
+            Driver::AyrtonSenna => f.write_str("Ayrton Senna"),
+            Driver::NobuharuMatsushita => f.write_str("Nobuharu Matsushita"),
+            Driver::NikitaMazepin => f.write_str("Nikita Mazepin"),
+            Driver::GuanyaZhou => f.write_str("Guanya Zhou"),
+            Driver::MickSchumacher => f.write_str("Mick Schumacher"),
+            Driver::CallumIlott => f.write_str("Callum Ilott"),
+            Driver::JuanManuelCorrea => f.write_str("Juan Manuel Correa"),
+            Driver::JordanKing => f.write_str("Jordan King"),
+            Driver::MahaveerRaghunathan => f.write_str("Mahaveer Raghunathan"),
+            Driver::TatianaCalderon => f.write_str("Tatiana Calderon"),
+            Driver::AnthoineHubert => f.write_str("Anthoine Hubert"),
+            Driver::GuilianoAlesi => f.write_str("Guiliano Alesi"),
+            Driver::RalphBoschung => f.write_str("Ralph Boschung"),
+            Driver::MichaelSchumacher => f.write_str("Michael Schumacher"),
+            Driver::DanTicktum => f.write_str("Dan Ticktum"),
+            Driver::MarcusArmstrong => f.write_str("Marcus Armstrong"),
+            Driver::ChristianLundgaard => f.write_str("Christian Lundgaard"),
+            Driver::YukiTsunoda => f.write_str("Yuki Tsunoda"),
+            Driver::JehanDaruvala => f.write_str("Jehan Daruvala"),
+            Driver::GulhermeSamaia => f.write_str("Gulherme Samaia"),
+            Driver::PedroPiquet => f.write_str("Pedro Piquet"),
+            Driver::FelipeDrugovich => f.write_str("Felipe Drugovich"),
+            Driver::RobertSchwartzman => f.write_str("Robert Schwartzman"),
+            Driver::RoyNissany => f.write_str("Roy Nissany"),
+            Driver::MarinoSato => f.write_str("Marino Sato"),
+            Driver::AidanJackson => f.write_str("Aidan Jackson"),
+            Driver::CasperAkkerman => f.write_str("Casper Akkerman"),
+            Driver::JensonButton => f.write_str("Jenson Button"),
+            Driver::DavidCoulthard => f.write_str("David Coulthard"),
+            Driver::NicoRosberg => f.write_str("Nico Rosberg"),
+            Driver::OscarPiastri => f.write_str("Oscar Piastri"),
+            Driver::LiamLawson => f.write_str("Liam Lawson"),
+            Driver::JuriVips => f.write_str("Juri Vips"),
+            Driver::TheoPourchaire => f.write_str("Theo Pourchaire"),
+            Driver::RichardVerschoor => f.write_str("Richard Verschoor"),
+            Driver::LirimZendeli => f.write_str("Lirim Zendeli"),
+            Driver::DavidBeckmann => f.write_str("David Beckmann"),
+            Driver::AlessioDeledda => f.write_str("Alessio Deledda"),
+            Driver::BentViscaal => f.write_str("Bent Viscaal"),
+            Driver::EnzoFittipaldi => f.write_str("Enzo Fittipaldi"),
+            Driver::MarkWebber => f.write_str("Mark Webber"),
+            Driver::JacquesVilleneuve => f.write_str("Jacques Villeneuve"),
+            Driver::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+/// Track ids, used in Session packets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Track {
+    Melbourne,
+    PaulRicard,
+    Shanghai,
+    Sakhir,
+    Catalunya,
+    Monaco,
+    Montreal,
+    Silverstone,
+    Hockenheim,
+    Hungaroring,
+    Spa,
+    Monza,
+    Singapore,
+    Suzuka,
+    AbuDhabi,
+    Texas,
+    Brazil,
+    Austria,
+    Sochi,
+    Mexico,
+    Baku,
+    SakhirShort,
+    SilverstoneShort,
+    TexasShort,
+    SuzukaShort,
+    Hanoi,
+    Zandvoort,
+    Imola,
+    Portimao,
+    Jeddah,
+    /// Value not listed in the game's appendix, the game sends -1 (255) for an unknown track
+    Unknown(u8),
+}
+
+impl Track {
+    /// Three letter code of the country or event, defined by this crate after the IOC country codes
+    /// Short layouts share the code of the full circuit
+    pub fn short_code(&self) -> Option<&'static str> {
+        match self {
+            Track::Melbourne => Some("AUS"),
+            Track::PaulRicard => Some("FRA"),
+            Track::Shanghai => Some("CHN"),
+            Track::Sakhir => Some("BHR"),
+            Track::Catalunya => Some("ESP"),
+            Track::Monaco => Some("MON"),
+            Track::Montreal => Some("CAN"),
+            Track::Silverstone => Some("GBR"),
+            Track::Hockenheim => Some("GER"),
+            Track::Hungaroring => Some("HUN"),
+            Track::Spa => Some("BEL"),
+            Track::Monza => Some("ITA"),
+            Track::Singapore => Some("SGP"),
+            Track::Suzuka => Some("JPN"),
+            Track::AbuDhabi => Some("UAE"),
+            Track::Texas => Some("USA"),
+            Track::Brazil => Some("BRA"),
+            Track::Austria => Some("AUT"),
+            Track::Sochi => Some("RUS"),
+            Track::Mexico => Some("MEX"),
+            Track::Baku => Some("AZE"),
+            Track::SakhirShort => Some("BHR"),
+            Track::SilverstoneShort => Some("GBR"),
+            Track::TexasShort => Some("USA"),
+            Track::SuzukaShort => Some("JPN"),
+            Track::Hanoi => Some("VIE"),
+            Track::Zandvoort => Some("NED"),
+            Track::Imola => Some("EMI"),
+            Track::Portimao => Some("POR"),
+            Track::Jeddah => Some("KSA"),
+            Track::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<u8> for Track {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Track::Melbourne,
+            1 => Track::PaulRicard,
+            2 => Track::Shanghai,
+            3 => Track::Sakhir,
+            4 => Track::Catalunya,
+            5 => Track::Monaco,
+            6 => Track::Montreal,
+            7 => Track::Silverstone,
+            8 => Track::Hockenheim,
+            9 => Track::Hungaroring,
+            10 => Track::Spa,
+            11 => Track::Monza,
+            12 => Track::Singapore,
+            13 => Track::Suzuka,
+            14 => Track::AbuDhabi,
+            15 => Track::Texas,
+            16 => Track::Brazil,
+            17 => Track::Austria,
+            18 => Track::Sochi,
+            19 => Track::Mexico,
+            20 => Track::Baku,
+            21 => Track::SakhirShort,
+            22 => Track::SilverstoneShort,
+            23 => Track::TexasShort,
+            24 => Track::SuzukaShort,
+            25 => Track::Hanoi,
+            26 => Track::Zandvoort,
+            27 => Track::Imola,
+            28 => Track::Portimao,
+            29 => Track::Jeddah,
+            value => Track::Unknown(value),
+        }
+    }
+}
+
+impl From<Track> for u8 {
+    fn from(value: Track) -> Self {
+        match value {
+            Track::Melbourne => 0,
+            Track::PaulRicard => 1,
+            Track::Shanghai => 2,
+            Track::Sakhir => 3,
+            Track::Catalunya => 4,
+            Track::Monaco => 5,
+            Track::Montreal => 6,
+            Track::Silverstone => 7,
+            Track::Hockenheim => 8,
+            Track::Hungaroring => 9,
+            Track::Spa => 10,
+            Track::Monza => 11,
+            Track::Singapore => 12,
+            Track::Suzuka => 13,
+            Track::AbuDhabi => 14,
+            Track::Texas => 15,
+            Track::Brazil => 16,
+            Track::Austria => 17,
+            Track::Sochi => 18,
+            Track::Mexico => 19,
+            Track::Baku => 20,
+            Track::SakhirShort => 21,
+            Track::SilverstoneShort => 22,
+            Track::TexasShort => 23,
+            Track::SuzukaShort => 24,
+            Track::Hanoi => 25,
+            Track::Zandvoort => 26,
+            Track::Imola => 27,
+            Track::Portimao => 28,
+            Track::Jeddah => 29,
+            Track::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Track {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Track::Melbourne => f.write_str("Melbourne"),
+            Track::PaulRicard => f.write_str("Paul Ricard"),
+            Track::Shanghai => f.write_str("Shanghai"),
+            Track::Sakhir => f.write_str("Sakhir (Bahrain)"),
+            Track::Catalunya => f.write_str("Catalunya"),
+            Track::Monaco => f.write_str("Monaco"),
+            Track::Montreal => f.write_str("Montreal"),
+            Track::Silverstone => f.write_str("Silverstone"),
+            Track::Hockenheim => f.write_str("Hockenheim"),
+            Track::Hungaroring => f.write_str("Hungaroring"),
+            Track::Spa => f.write_str("Spa"),
+            Track::Monza => f.write_str("Monza"),
+            Track::Singapore => f.write_str("Singapore"),
+            Track::Suzuka => f.write_str("Suzuka"),
+            Track::AbuDhabi => f.write_str("Abu Dhabi"),
+            Track::Texas => f.write_str("Texas"),
+            Track::Brazil => f.write_str("Brazil"),
+            Track::Austria => f.write_str("Austria"),
+            Track::Sochi => f.write_str("Sochi"),
+            Track::Mexico => f.write_str("Mexico"),
+            Track::Baku => f.write_str("Baku (Azerbaijan)"),
+            Track::SakhirShort => f.write_str("Sakhir Short"),
+            Track::SilverstoneShort => f.write_str("Silverstone Short"),
+            Track::TexasShort => f.write_str("Texas Short"),
+            Track::SuzukaShort => f.write_str("Suzuka Short"),
+            Track::Hanoi => f.write_str("Hanoi"),
+            Track::Zandvoort => f.write_str("Zandvoort"),
+            Track::Imola => f.write_str("Imola"),
+            Track::Portimao => f.write_str("Portimão"),
+            Track::Jeddah => f.write_str("Jeddah"),
+            Track::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+/// Nationality ids, used in Participants and Lobby Info packets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nationality {
+    American,
+    Argentinean,
+    Australian,
+    Austrian,
+    Azerbaijani,
+    Bahraini,
+    Belgian,
+    Bolivian,
+    Brazilian,
+    British,
+    Bulgarian,
+    Cameroonian,
+    Canadian,
+    Chilean,
+    Chinese,
+    Colombian,
+    CostaRican,
+    Croatian,
+    Cypriot,
+    Czech,
+    Danish,
+    Dutch,
+    Ecuadorian,
+    English,
+    Emirian,
+    Estonian,
+    Finnish,
+    French,
+    German,
+    Ghanaian,
+    Greek,
+    Guatemalan,
+    Honduran,
+    HongKonger,
+    Hungarian,
+    Icelander,
+    Indian,
+    Indonesian,
+    Irish,
+    Israeli,
+    Italian,
+    Jamaican,
+    Japanese,
+    Jordanian,
+    Kuwaiti,
+    Latvian,
+    Lebanese,
+    Lithuanian,
+    Luxembourger,
+    Malaysian,
+    Maltese,
+    Mexican,
+    Monegasque,
+    NewZealander,
+    Nicaraguan,
+    NorthernIrish,
+    Norwegian,
+    Omani,
+    Pakistani,
+    Panamanian,
+    Paraguayan,
+    Peruvian,
+    Polish,
+    Portuguese,
+    Qatari,
+    Romanian,
+    Russian,
+    Salvadoran,
+    Saudi,
+    Scottish,
+    Serbian,
+    Singaporean,
+    Slovakian,
+    Slovenian,
+    SouthKorean,
+    SouthAfrican,
+    Spanish,
+    Swedish,
+    Swiss,
+    Thai,
+    Turkish,
+    Uruguayan,
+    Ukrainian,
+    Venezuelan,
+    Barbadian,
+    Welsh,
+    Vietnamese,
+    /// Value not listed in the game's appendix, 0 when not set
+    Unknown(u8),
+}
+
+impl Nationality {
+    /// Three letter country code, defined by this crate after the IOC country codes
+    pub fn short_code(&self) -> Option<&'static str> {
+        match self {
+            Nationality::American => Some("USA"),
+            Nationality::Argentinean => Some("ARG"),
+            Nationality::Australian => Some("AUS"),
+            Nationality::Austrian => Some("AUT"),
+            Nationality::Azerbaijani => Some("AZE"),
+            Nationality::Bahraini => Some("BRN"),
+            Nationality::Belgian => Some("BEL"),
+            Nationality::Bolivian => Some("BOL"),
+            Nationality::Brazilian => Some("BRA"),
+            Nationality::British => Some("GBR"),
+            Nationality::Bulgarian => Some("BUL"),
+            Nationality::Cameroonian => Some("CMR"),
+            Nationality::Canadian => Some("CAN"),
+            Nationality::Chilean => Some("CHI"),
+            Nationality::Chinese => Some("CHN"),
+            Nationality::Colombian => Some("COL"),
+            Nationality::CostaRican => Some("CRC"),
+            Nationality::Croatian => Some("CRO"),
+            Nationality::Cypriot => Some("CYP"),
+            Nationality::Czech => Some("CZE"),
+            Nationality::Danish => Some("DEN"),
+            Nationality::Dutch => Some("NED"),
+            Nationality::Ecuadorian => Some("ECU"),
+            Nationality::English => Some("ENG"),
+            Nationality::Emirian => Some("UAE"),
+            Nationality::Estonian => Some("EST"),
+            Nationality::Finnish => Some("FIN"),
+            Nationality::French => Some("FRA"),
+            Nationality::German => Some("GER"),
+            Nationality::Ghanaian => Some("GHA"),
+            Nationality::Greek => Some("GRE"),
+            Nationality::Guatemalan => Some("GUA"),
+            Nationality::Honduran => Some("HON"),
+            Nationality::HongKonger => Some("HKG"),
+            Nationality::Hungarian => Some("HUN"),
+            Nationality::Icelander => Some("ISL"),
+            Nationality::Indian => Some("IND"),
+            Nationality::Indonesian => Some("INA"),
+            Nationality::Irish => Some("IRL"),
+            Nationality::Israeli => Some("ISR"),
+            Nationality::Italian => Some("ITA"),
+            Nationality::Jamaican => Some("JAM"),
+            Nationality::Japanese => Some("JPN"),
+            Nationality::Jordanian => Some("JOR"),
+            Nationality::Kuwaiti => Some("KUW"),
+            Nationality::Latvian => Some("LAT"),
+            Nationality::Lebanese => Some("LBN"),
+            Nationality::Lithuanian => Some("LTU"),
+            Nationality::Luxembourger => Some("LUX"),
+            Nationality::Malaysian => Some("MAS"),
+            Nationality::Maltese => Some("MLT"),
+            Nationality::Mexican => Some("MEX"),
+            Nationality::Monegasque => Some("MON"),
+            Nationality::NewZealander => Some("NZL"),
+            Nationality::Nicaraguan => Some("NCA"),
+            Nationality::NorthernIrish => Some("NIR"),
+            Nationality::Norwegian => Some("NOR"),
+            Nationality::Omani => Some("OMA"),
+            Nationality::Pakistani => Some("PAK"),
+            Nationality::Panamanian => Some("PAN"),
+            Nationality::Paraguayan => Some("PAR"),
+            Nationality::Peruvian => Some("PER"),
+            Nationality::Polish => Some("POL"),
+            Nationality::Portuguese => Some("POR"),
+            Nationality::Qatari => Some("QAT"),
+            Nationality::Romanian => Some("ROU"),
+            Nationality::Russian => Some("RUS"),
+            Nationality::Salvadoran => Some("ESA"),
+            Nationality::Saudi => Some("KSA"),
+            Nationality::Scottish => Some("SCO"),
+            Nationality::Serbian => Some("SRB"),
+            Nationality::Singaporean => Some("SGP"),
+            Nationality::Slovakian => Some("SVK"),
+            Nationality::Slovenian => Some("SLO"),
+            Nationality::SouthKorean => Some("KOR"),
+            Nationality::SouthAfrican => Some("RSA"),
+            Nationality::Spanish => Some("ESP"),
+            Nationality::Swedish => Some("SWE"),
+            Nationality::Swiss => Some("SUI"),
+            Nationality::Thai => Some("THA"),
+            Nationality::Turkish => Some("TUR"),
+            Nationality::Uruguayan => Some("URU"),
+            Nationality::Ukrainian => Some("UKR"),
+            Nationality::Venezuelan => Some("VEN"),
+            Nationality::Barbadian => Some("BAR"),
+            Nationality::Welsh => Some("WAL"),
+            Nationality::Vietnamese => Some("VIE"),
+            Nationality::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<u8> for Nationality {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Nationality::American,
+            2 => Nationality::Argentinean,
+            3 => Nationality::Australian,
+            4 => Nationality::Austrian,
+            5 => Nationality::Azerbaijani,
+            6 => Nationality::Bahraini,
+            7 => Nationality::Belgian,
+            8 => Nationality::Bolivian,
+            9 => Nationality::Brazilian,
+            10 => Nationality::British,
+            11 => Nationality::Bulgarian,
+            12 => Nationality::Cameroonian,
+            13 => Nationality::Canadian,
+            14 => Nationality::Chilean,
+            15 => Nationality::Chinese,
+            16 => Nationality::Colombian,
+            17 => Nationality::CostaRican,
+            18 => Nationality::Croatian,
+            19 => Nationality::Cypriot,
+            20 => Nationality::Czech,
+            21 => Nationality::Danish,
+            22 => Nationality::Dutch,
+            23 => Nationality::Ecuadorian,
+            24 => Nationality::English,
+            25 => Nationality::Emirian,
+            26 => Nationality::Estonian,
+            27 => Nationality::Finnish,
+            28 => Nationality::French,
+            29 => Nationality::German,
+            30 => Nationality::Ghanaian,
+            31 => Nationality::Greek,
+            32 => Nationality::Guatemalan,
+            33 => Nationality::Honduran,
+            34 => Nationality::HongKonger,
+            35 => Nationality::Hungarian,
+            36 => Nationality::Icelander,
+            37 => Nationality::Indian,
+            38 => Nationality::Indonesian,
+            39 => Nationality::Irish,
+            40 => Nationality::Israeli,
+            41 => Nationality::Italian,
+            42 => Nationality::Jamaican,
+            43 => Nationality::Japanese,
+            44 => Nationality::Jordanian,
+            45 => Nationality::Kuwaiti,
+            46 => Nationality::Latvian,
+            47 => Nationality::Lebanese,
+            48 => Nationality::Lithuanian,
+            49 => Nationality::Luxembourger,
+            50 => Nationality::Malaysian,
+            51 => Nationality::Maltese,
+            52 => Nationality::Mexican,
+            53 => Nationality::Monegasque,
+            54 => Nationality::NewZealander,
+            55 => Nationality::Nicaraguan,
+            56 => Nationality::NorthernIrish,
+            57 => Nationality::Norwegian,
+            58 => Nationality::Omani,
+            59 => Nationality::Pakistani,
+            60 => Nationality::Panamanian,
+            61 => Nationality::Paraguayan,
+            62 => Nationality::Peruvian,
+            63 => Nationality::Polish,
+            64 => Nationality::Portuguese,
+            65 => Nationality::Qatari,
+            66 => Nationality::Romanian,
+            67 => Nationality::Russian,
+            68 => Nationality::Salvadoran,
+            69 => Nationality::Saudi,
+            70 => Nationality::Scottish,
+            71 => Nationality::Serbian,
+            72 => Nationality::Singaporean,
+            73 => Nationality::Slovakian,
+            74 => Nationality::Slovenian,
+            75 => Nationality::SouthKorean,
+            76 => Nationality::SouthAfrican,
+            77 => Nationality::Spanish,
+            78 => Nationality::Swedish,
+            79 => Nationality::Swiss,
+            80 => Nationality::Thai,
+            81 => Nationality::Turkish,
+            82 => Nationality::Uruguayan,
+            83 => Nationality::Ukrainian,
+            84 => Nationality::Venezuelan,
+            85 => Nationality::Barbadian,
+            86 => Nationality::Welsh,
+            87 => Nationality::Vietnamese,
+            value => Nationality::Unknown(value),
+        }
+    }
+}
+
+impl From<Nationality> for u8 {
+    fn from(value: Nationality) -> Self {
+        match value {
+            Nationality::American => 1,
+            Nationality::Argentinean => 2,
+            Nationality::Australian => 3,
+            Nationality::Austrian => 4,
+            Nationality::Azerbaijani => 5,
+            Nationality::Bahraini => 6,
+            Nationality::Belgian => 7,
+            Nationality::Bolivian => 8,
+            Nationality::Brazilian => 9,
+            Nationality::British => 10,
+            Nationality::Bulgarian => 11,
+            Nationality::Cameroonian => 12,
+            Nationality::Canadian => 13,
+            Nationality::Chilean => 14,
+            Nationality::Chinese => 15,
+            Nationality::Colombian => 16,
+            Nationality::CostaRican => 17,
+            Nationality::Croatian => 18,
+            Nationality::Cypriot => 19,
+            Nationality::Czech => 20,
+            Nationality::Danish => 21,
+            Nationality::Dutch => 22,
+            Nationality::Ecuadorian => 23,
+            Nationality::English => 24,
+            Nationality::Emirian => 25,
+            Nationality::Estonian => 26,
+            Nationality::Finnish => 27,
+            Nationality::French => 28,
+            Nationality::German => 29,
+            Nationality::Ghanaian => 30,
+            Nationality::Greek => 31,
+            Nationality::Guatemalan => 32,
+            Nationality::Honduran => 33,
+            Nationality::HongKonger => 34,
+            Nationality::Hungarian => 35,
+            Nationality::Icelander => 36,
+            Nationality::Indian => 37,
+            Nationality::Indonesian => 38,
+            Nationality::Irish => 39,
+            Nationality::Israeli => 40,
+            Nationality::Italian => 41,
+            Nationality::Jamaican => 42,
+            Nationality::Japanese => 43,
+            Nationality::Jordanian => 44,
+            Nationality::Kuwaiti => 45,
+            Nationality::Latvian => 46,
+            Nationality::Lebanese => 47,
+            Nationality::Lithuanian => 48,
+            Nationality::Luxembourger => 49,
+            Nationality::Malaysian => 50,
+            Nationality::Maltese => 51,
+            Nationality::Mexican => 52,
+            Nationality::Monegasque => 53,
+            Nationality::NewZealander => 54,
+            Nationality::Nicaraguan => 55,
+            Nationality::NorthernIrish => 56,
+            Nationality::Norwegian => 57,
+            Nationality::Omani => 58,
+            Nationality::Pakistani => 59,
+            Nationality::Panamanian => 60,
+            Nationality::Paraguayan => 61,
+            Nationality::Peruvian => 62,
+            Nationality::Polish => 63,
+            Nationality::Portuguese => 64,
+            Nationality::Qatari => 65,
+            Nationality::Romanian => 66,
+            Nationality::Russian => 67,
+            Nationality::Salvadoran => 68,
+            Nationality::Saudi => 69,
+            Nationality::Scottish => 70,
+            Nationality::Serbian => 71,
+            Nationality::Singaporean => 72,
+            Nationality::Slovakian => 73,
+            Nationality::Slovenian => 74,
+            Nationality::SouthKorean => 75,
+            Nationality::SouthAfrican => 76,
+            Nationality::Spanish => 77,
+            Nationality::Swedish => 78,
+            Nationality::Swiss => 79,
+            Nationality::Thai => 80,
+            Nationality::Turkish => 81,
+            Nationality::Uruguayan => 82,
+            Nationality::Ukrainian => 83,
+            Nationality::Venezuelan => 84,
+            Nationality::Barbadian => 85,
+            Nationality::Welsh => 86,
+            Nationality::Vietnamese => 87,
+            Nationality::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Nationality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Nationality::American => f.write_str("American"),
+            Nationality::Argentinean => f.write_str("Argentinean"),
+            Nationality::Australian => f.write_str("Australian"),
+            Nationality::Austrian => f.write_str("Austrian"),
+            Nationality::Azerbaijani => f.write_str("Azerbaijani"),
+            Nationality::Bahraini => f.write_str("Bahraini"),
+            Nationality::Belgian => f.write_str("Belgian"),
+            Nationality::Bolivian => f.write_str("Bolivian"),
+            Nationality::Brazilian => f.write_str("Brazilian"),
+            Nationality::British => f.write_str("British"),
+            Nationality::Bulgarian => f.write_str("Bulgarian"),
+            Nationality::Cameroonian => f.write_str("Cameroonian"),
+            Nationality::Canadian => f.write_str("Canadian"),
+            Nationality::Chilean => f.write_str("Chilean"),
+            Nationality::Chinese => f.write_str("Chinese"),
+            Nationality::Colombian => f.write_str("Colombian"),
+            Nationality::CostaRican => f.write_str("Costa Rican"),
+            Nationality::Croatian => f.write_str("Croatian"),
+            Nationality::Cypriot => f.write_str("Cypriot"),
+            Nationality::Czech => f.write_str("Czech"),
+            Nationality::Danish => f.write_str("Danish"),
+            Nationality::Dutch => f.write_str("Dutch"),
+            Nationality::Ecuadorian => f.write_str("Ecuadorian"),
+            Nationality::English => f.write_str("English"),
+            Nationality::Emirian => f.write_str("Emirian"),
+            Nationality::Estonian => f.write_str("Estonian"),
+            Nationality::Finnish => f.write_str("Finnish"),
+            Nationality::French => f.write_str("French"),
+            Nationality::German => f.write_str("German"),
+            Nationality::Ghanaian => f.write_str("Ghanaian"),
+            Nationality::Greek => f.write_str("Greek"),
+            Nationality::Guatemalan => f.write_str("Guatemalan"),
+            Nationality::Honduran => f.write_str("Honduran"),
+            Nationality::HongKonger => f.write_str("Hong Konger"),
+            Nationality::Hungarian => f.write_str("Hungarian"),
+            Nationality::Icelander => f.write_str("Icelander"),
+            Nationality::Indian => f.write_str("Indian"),
+            Nationality::Indonesian => f.write_str("Indonesian"),
+            Nationality::Irish => f.write_str("Irish"),
+            Nationality::Israeli => f.write_str("Israeli"),
+            Nationality::Italian => f.write_str("Italian"),
+            Nationality::Jamaican => f.write_str("Jamaican"),
+            Nationality::Japanese => f.write_str("Japanese"),
+            Nationality::Jordanian => f.write_str("Jordanian"),
+            Nationality::Kuwaiti => f.write_str("Kuwaiti"),
+            Nationality::Latvian => f.write_str("Latvian"),
+            Nationality::Lebanese => f.write_str("Lebanese"),
+            Nationality::Lithuanian => f.write_str("Lithuanian"),
+            Nationality::Luxembourger => f.write_str("Luxembourger"),
+            Nationality::Malaysian => f.write_str("Malaysian"),
+            Nationality::Maltese => f.write_str("Maltese"),
+            Nationality::Mexican => f.write_str("Mexican"),
+            Nationality::Monegasque => f.write_str("Monegasque"),
+            Nationality::NewZealander => f.write_str("New Zealander"),
+            Nationality::Nicaraguan => f.write_str("Nicaraguan"),
+            Nationality::NorthernIrish => f.write_str("Northern Irish"),
+            Nationality::Norwegian => f.write_str("Norwegian"),
+            Nationality::Omani => f.write_str("Omani"),
+            Nationality::Pakistani => f.write_str("Pakistani"),
+            Nationality::Panamanian => f.write_str("Panamanian"),
+            Nationality::Paraguayan => f.write_str("Paraguayan"),
+            Nationality::Peruvian => f.write_str("Peruvian"),
+            Nationality::Polish => f.write_str("Polish"),
+            Nationality::Portuguese => f.write_str("Portuguese"),
+            Nationality::Qatari => f.write_str("Qatari"),
+            Nationality::Romanian => f.write_str("Romanian"),
+            Nationality::Russian => f.write_str("Russian"),
+            Nationality::Salvadoran => f.write_str("Salvadoran"),
+            Nationality::Saudi => f.write_str("Saudi"),
+            Nationality::Scottish => f.write_str("Scottish"),
+            Nationality::Serbian => f.write_str("Serbian"),
+            Nationality::Singaporean => f.write_str("Singaporean"),
+            Nationality::Slovakian => f.write_str("Slovakian"),
+            Nationality::Slovenian => f.write_str("Slovenian"),
+            Nationality::SouthKorean => f.write_str("South Korean"),
+            Nationality::SouthAfrican => f.write_str("South African"),
+            Nationality::Spanish => f.write_str("Spanish"),
+            Nationality::Swedish => f.write_str("Swedish"),
+            Nationality::Swiss => f.write_str("Swiss"),
+            Nationality::Thai => f.write_str("Thai"),
+            Nationality::Turkish => f.write_str("Turkish"),
+            Nationality::Uruguayan => f.write_str("Uruguayan"),
+            Nationality::Ukrainian => f.write_str("Ukrainian"),
+            Nationality::Venezuelan => f.write_str("Venezuelan"),
+            Nationality::Barbadian => f.write_str("Barbadian"),
+            Nationality::Welsh => f.write_str("Welsh"),
+            Nationality::Vietnamese => f.write_str("Vietnamese"),
+            Nationality::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#![allow(clippy::needless_range_loop)]
+
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::utils::*;
+
+pub const CAR_DAMAGE_PACKET_SIZE: usize = 882 - packet::header::HEADER_SIZE;
+
+pub fn parse_car_damage_packet(buf: &mut impl Buf) -> Result<DamageData, F1Error> {
+    if buf.remaining() < CAR_DAMAGE_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let mut car_damage_data: [CarDamageData; NUMBER_OF_CARS] =
+        [CarDamageData::default(); NUMBER_OF_CARS];
+
+    for i in 0..NUMBER_OF_CARS {
+        let tyres_wear = parse_wheels_data_f32(buf);
+        let tyres_damage = parse_wheels_data_u8(buf);
+        let brakes_damage = parse_wheels_data_u8(buf);
+        let front_left_wing_damage = buf.get_u8();
+        let front_right_wing_damage = buf.get_u8();
+        let rear_wing_damage = buf.get_u8();
+        let floor_damage = buf.get_u8();
+        let diffuser_damage = buf.get_u8();
+        let sidepod_damage = buf.get_u8();
+        let drs_fault = buf.get_u8() == 1;
+        let gear_box_damage = buf.get_u8();
+        let engine_damage = buf.get_u8();
+        let engine_mguh_wear = buf.get_u8();
+        let engine_es_wear = buf.get_u8();
+        let engine_ce_wear = buf.get_u8();
+        let engine_ice_wear = buf.get_u8();
+        let engine_mguk_wear = buf.get_u8();
+        let engine_tc_wear = buf.get_u8();
+
+        car_damage_data[i] = CarDamageData {
+            tyres_wear,
+            tyres_damage,
+            brakes_damage,
+            front_left_wing_damage,
+            front_right_wing_damage,
+            rear_wing_damage,
+            floor_damage,
+            diffuser_damage,
+            sidepod_damage,
+            drs_fault,
+            gear_box_damage,
+            engine_damage,
+            engine_mguh_wear,
+            engine_es_wear,
+            engine_ce_wear,
+            engine_ice_wear,
+            engine_mguk_wear,
+            engine_tc_wear,
+        };
+    }
+
+    Ok(DamageData { car_damage_data })
+}
+
+pub fn write_car_damage_packet(data: &DamageData, buf: &mut BytesMut) {
+    for car in data.car_damage_data.iter() {
+        write_wheels_data_f32(buf, &car.tyres_wear);
+        write_wheels_data_u8(buf, &car.tyres_damage);
+        write_wheels_data_u8(buf, &car.brakes_damage);
+        buf.put_u8(car.front_left_wing_damage);
+        buf.put_u8(car.front_right_wing_damage);
+        buf.put_u8(car.rear_wing_damage);
+        buf.put_u8(car.floor_damage);
+        buf.put_u8(car.diffuser_damage);
+        buf.put_u8(car.sidepod_damage);
+        buf.put_u8(car.drs_fault as u8);
+        buf.put_u8(car.gear_box_damage);
+        buf.put_u8(car.engine_damage);
+        buf.put_u8(car.engine_mguh_wear);
+        buf.put_u8(car.engine_es_wear);
+        buf.put_u8(car.engine_ce_wear);
+        buf.put_u8(car.engine_ice_wear);
+        buf.put_u8(car.engine_mguk_wear);
+        buf.put_u8(car.engine_tc_wear);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DamageData {
+    pub car_damage_data: [CarDamageData; NUMBER_OF_CARS],
+}
+
+/// Wear and damage values are percentages
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarDamageData {
+    pub tyres_wear: WheelsData<f32>,
+    pub tyres_damage: WheelsData<u8>,
+    pub brakes_damage: WheelsData<u8>,
+    pub front_left_wing_damage: u8,
+    pub front_right_wing_damage: u8,
+    pub rear_wing_damage: u8,
+    pub floor_damage: u8,
+    pub diffuser_damage: u8,
+    pub sidepod_damage: u8,
+    pub drs_fault: bool,
+    pub gear_box_damage: u8,
+    pub engine_damage: u8,
+    pub engine_mguh_wear: u8,
+    pub engine_es_wear: u8,
+    pub engine_ce_wear: u8,
+    pub engine_ice_wear: u8,
+    pub engine_mguk_wear: u8,
+    pub engine_tc_wear: u8,
+}
@@ -0,0 +1,117 @@
+#![allow(clippy::needless_range_loop)]
+
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::utils::*;
+
+pub const CAR_SETUPS_PACKET_SIZE: usize = 1102 - packet::header::HEADER_SIZE;
+
+pub fn parse_car_setups_packet(buf: &mut impl Buf) -> Result<CarSetupsData, F1Error> {
+    if buf.remaining() < CAR_SETUPS_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let mut car_setups: [CarSetupData; NUMBER_OF_CARS] = [CarSetupData::default(); NUMBER_OF_CARS];
+
+    for i in 0..NUMBER_OF_CARS {
+        let front_wing = buf.get_u8();
+        let rear_wing = buf.get_u8();
+        let on_throttle = buf.get_u8();
+        let off_throttle = buf.get_u8();
+        let front_camber = buf.get_f32_le();
+        let rear_camber = buf.get_f32_le();
+        let front_toe = buf.get_f32_le();
+        let rear_toe = buf.get_f32_le();
+        let front_suspension = buf.get_u8();
+        let rear_suspension = buf.get_u8();
+        let front_anti_roll_bar = buf.get_u8();
+        let rear_anti_roll_bar = buf.get_u8();
+        let front_suspension_height = buf.get_u8();
+        let rear_suspension_height = buf.get_u8();
+        let brake_pressure = buf.get_u8();
+        let brake_bias = buf.get_u8();
+        let tyres_pressure = parse_wheels_data_f32(buf);
+        let ballast = buf.get_u8();
+        let fuel_load = buf.get_f32_le();
+
+        car_setups[i] = CarSetupData {
+            front_wing,
+            rear_wing,
+            on_throttle,
+            off_throttle,
+            front_camber,
+            rear_camber,
+            front_toe,
+            rear_toe,
+            front_suspension,
+            rear_suspension,
+            front_anti_roll_bar,
+            rear_anti_roll_bar,
+            front_suspension_height,
+            rear_suspension_height,
+            brake_pressure,
+            brake_bias,
+            tyres_pressure,
+            ballast,
+            fuel_load,
+        };
+    }
+
+    Ok(CarSetupsData { car_setups })
+}
+
+pub fn write_car_setups_packet(data: &CarSetupsData, buf: &mut BytesMut) {
+    for car in data.car_setups.iter() {
+        buf.put_u8(car.front_wing);
+        buf.put_u8(car.rear_wing);
+        buf.put_u8(car.on_throttle);
+        buf.put_u8(car.off_throttle);
+        buf.put_f32_le(car.front_camber);
+        buf.put_f32_le(car.rear_camber);
+        buf.put_f32_le(car.front_toe);
+        buf.put_f32_le(car.rear_toe);
+        buf.put_u8(car.front_suspension);
+        buf.put_u8(car.rear_suspension);
+        buf.put_u8(car.front_anti_roll_bar);
+        buf.put_u8(car.rear_anti_roll_bar);
+        buf.put_u8(car.front_suspension_height);
+        buf.put_u8(car.rear_suspension_height);
+        buf.put_u8(car.brake_pressure);
+        buf.put_u8(car.brake_bias);
+        write_wheels_data_f32(buf, &car.tyres_pressure);
+        buf.put_u8(car.ballast);
+        buf.put_f32_le(car.fuel_load);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarSetupsData {
+    pub car_setups: [CarSetupData; NUMBER_OF_CARS],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarSetupData {
+    pub front_wing: u8,
+    pub rear_wing: u8,
+    pub on_throttle: u8,
+    pub off_throttle: u8,
+    pub front_camber: f32,
+    pub rear_camber: f32,
+    pub front_toe: f32,
+    pub rear_toe: f32,
+    pub front_suspension: u8,
+    pub rear_suspension: u8,
+    pub front_anti_roll_bar: u8,
+    pub rear_anti_roll_bar: u8,
+    pub front_suspension_height: u8,
+    pub rear_suspension_height: u8,
+    pub brake_pressure: u8,
+    pub brake_bias: u8,
+    pub tyres_pressure: WheelsData<f32>,
+    pub ballast: u8,
+    pub fuel_load: f32,
+}
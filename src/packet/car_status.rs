@@ -0,0 +1,134 @@
+#![allow(clippy::needless_range_loop)]
+
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::utils::*;
+
+pub const CAR_STATUS_PACKET_SIZE: usize = 1058 - packet::header::HEADER_SIZE;
+
+pub fn parse_car_status_packet(buf: &mut impl Buf) -> Result<StatusData, F1Error> {
+    if buf.remaining() < CAR_STATUS_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let mut car_status_data: [CarStatusData; NUMBER_OF_CARS] =
+        [CarStatusData::default(); NUMBER_OF_CARS];
+
+    for i in 0..NUMBER_OF_CARS {
+        let traction_control = buf.get_u8();
+        let anti_lock_brakes = buf.get_u8() == 1;
+        let fuel_mix = buf.get_u8();
+        let front_brake_bias = buf.get_u8();
+        let pit_limiter_status = buf.get_u8() == 1;
+        let fuel_in_tank = buf.get_f32_le();
+        let fuel_capacity = buf.get_f32_le();
+        let fuel_remaining_laps = buf.get_f32_le();
+        let max_rpm = buf.get_u16_le();
+        let idle_rpm = buf.get_u16_le();
+        let max_gears = buf.get_u8();
+        let drs_allowed = buf.get_u8() == 1;
+        let drs_activation_distance = buf.get_u16_le();
+        let actual_tyre_compound = buf.get_u8();
+        let visual_tyre_compound = buf.get_u8();
+        let tyres_age_laps = buf.get_u8();
+        let vehicle_fia_flags = buf.get_i8();
+        let ers_store_energy = buf.get_f32_le();
+        let ers_deploy_mode = buf.get_u8();
+        let ers_harvested_this_lap_mguk = buf.get_f32_le();
+        let ers_harvested_this_lap_mguh = buf.get_f32_le();
+        let ers_deployed_this_lap = buf.get_f32_le();
+        let network_paused = buf.get_u8() == 1;
+
+        car_status_data[i] = CarStatusData {
+            traction_control,
+            anti_lock_brakes,
+            fuel_mix,
+            front_brake_bias,
+            pit_limiter_status,
+            fuel_in_tank,
+            fuel_capacity,
+            fuel_remaining_laps,
+            max_rpm,
+            idle_rpm,
+            max_gears,
+            drs_allowed,
+            drs_activation_distance,
+            actual_tyre_compound,
+            visual_tyre_compound,
+            tyres_age_laps,
+            vehicle_fia_flags,
+            ers_store_energy,
+            ers_deploy_mode,
+            ers_harvested_this_lap_mguk,
+            ers_harvested_this_lap_mguh,
+            ers_deployed_this_lap,
+            network_paused,
+        };
+    }
+
+    Ok(StatusData { car_status_data })
+}
+
+pub fn write_car_status_packet(data: &StatusData, buf: &mut BytesMut) {
+    for car in data.car_status_data.iter() {
+        buf.put_u8(car.traction_control);
+        buf.put_u8(car.anti_lock_brakes as u8);
+        buf.put_u8(car.fuel_mix);
+        buf.put_u8(car.front_brake_bias);
+        buf.put_u8(car.pit_limiter_status as u8);
+        buf.put_f32_le(car.fuel_in_tank);
+        buf.put_f32_le(car.fuel_capacity);
+        buf.put_f32_le(car.fuel_remaining_laps);
+        buf.put_u16_le(car.max_rpm);
+        buf.put_u16_le(car.idle_rpm);
+        buf.put_u8(car.max_gears);
+        buf.put_u8(car.drs_allowed as u8);
+        buf.put_u16_le(car.drs_activation_distance);
+        buf.put_u8(car.actual_tyre_compound);
+        buf.put_u8(car.visual_tyre_compound);
+        buf.put_u8(car.tyres_age_laps);
+        buf.put_i8(car.vehicle_fia_flags);
+        buf.put_f32_le(car.ers_store_energy);
+        buf.put_u8(car.ers_deploy_mode);
+        buf.put_f32_le(car.ers_harvested_this_lap_mguk);
+        buf.put_f32_le(car.ers_harvested_this_lap_mguh);
+        buf.put_f32_le(car.ers_deployed_this_lap);
+        buf.put_u8(car.network_paused as u8);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusData {
+    pub car_status_data: [CarStatusData; NUMBER_OF_CARS],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarStatusData {
+    pub traction_control: u8,
+    pub anti_lock_brakes: bool,
+    pub fuel_mix: u8,
+    pub front_brake_bias: u8,
+    pub pit_limiter_status: bool,
+    pub fuel_in_tank: f32,
+    pub fuel_capacity: f32,
+    pub fuel_remaining_laps: f32,
+    pub max_rpm: u16,
+    pub idle_rpm: u16,
+    pub max_gears: u8,
+    pub drs_allowed: bool,
+    pub drs_activation_distance: u16,
+    pub actual_tyre_compound: u8,
+    pub visual_tyre_compound: u8,
+    pub tyres_age_laps: u8,
+    pub vehicle_fia_flags: i8,
+    pub ers_store_energy: f32,
+    pub ers_deploy_mode: u8,
+    pub ers_harvested_this_lap_mguk: f32,
+    pub ers_harvested_this_lap_mguh: f32,
+    pub ers_deployed_this_lap: f32,
+    pub network_paused: bool,
+}
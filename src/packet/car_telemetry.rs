@@ -1,17 +1,19 @@
 #![allow(clippy::needless_range_loop)]
 
-use bytes::{Buf, BytesMut};
-use std::io::Cursor;
+use bytes::{Buf, BufMut, BytesMut};
+use core::fmt;
 
 use crate::error::F1Error;
 use crate::packet;
+use crate::packet::header::{parse_header, Header, PacketId};
 use crate::utils::*;
 
 pub const CAR_TELEMETRY_SIZE: usize = 1347 - packet::header::HEADER_SIZE;
 
-pub fn parse_car_telemetry_packet(
-    buf: &mut Cursor<&mut BytesMut>,
-) -> Result<TelemetryData, F1Error> {
+const CAR_TELEMETRY_DATA_SIZE: usize = 60;
+const CAR_TELEMETRY_DATA_END: usize = NUMBER_OF_CARS * CAR_TELEMETRY_DATA_SIZE;
+
+pub fn parse_car_telemetry_packet(buf: &mut impl Buf) -> Result<TelemetryData, F1Error> {
     if buf.remaining() < CAR_TELEMETRY_SIZE {
         return Err(F1Error::IncompleteData);
     }
@@ -35,7 +37,7 @@ pub fn parse_car_telemetry_packet(
         let tyres_inner_temp = parse_wheels_data_u8(buf);
         let engine_temp = buf.get_u16_le();
         let tyres_pressure = parse_wheels_data_f32(buf);
-        let surface_type = parse_wheels_data_u8(buf);
+        let surface_type = parse_wheels_data_u8(buf).map(SurfaceType::from);
 
         car_telemetry_data[i] = CarTelemetryData {
             speed,
@@ -71,7 +73,33 @@ pub fn parse_car_telemetry_packet(
     Ok(packet)
 }
 
+pub fn write_car_telemetry_packet(data: &TelemetryData, buf: &mut BytesMut) {
+    for car in data.car_telemetry_data.iter() {
+        buf.put_u16_le(car.speed);
+        buf.put_f32_le(car.throttle);
+        buf.put_f32_le(car.steer);
+        buf.put_f32_le(car.brake);
+        buf.put_u8(car.clutch);
+        buf.put_i8(car.gear);
+        buf.put_u16_le(car.engine_rpm);
+        buf.put_u8(car.drs as u8);
+        buf.put_u8(car.rev_lights_percent);
+        buf.put_u16_le(car.rev_lights_bit);
+        write_wheels_data_u16(buf, &car.brakes_temp);
+        write_wheels_data_u8(buf, &car.tyres_surface_temp);
+        write_wheels_data_u8(buf, &car.tyres_inner_temp);
+        buf.put_u16_le(car.engine_temp);
+        write_wheels_data_f32(buf, &car.tyres_pressure);
+        write_wheels_data_u8(buf, &car.surface_type.map(u8::from));
+    }
+
+    buf.put_u8(data.mfd_panel_index);
+    buf.put_u8(data.mfd_panel_index_secondary);
+    buf.put_i8(data.suggested_gear);
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TelemetryData {
     pub car_telemetry_data: [CarTelemetryData; NUMBER_OF_CARS],
     pub mfd_panel_index: u8,
@@ -80,6 +108,7 @@ pub struct TelemetryData {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CarTelemetryData {
     pub speed: u16,
     pub throttle: f32,
@@ -96,5 +125,289 @@ pub struct CarTelemetryData {
     pub tyres_inner_temp: WheelsData<u8>,
     pub engine_temp: u16,
     pub tyres_pressure: WheelsData<f32>,
-    pub surface_type: WheelsData<u8>,
+    pub surface_type: WheelsData<SurfaceType>,
+}
+
+/// Car Telemetry packet borrowed from the raw datagram, fields are decoded when they are read
+#[derive(Debug, Clone)]
+pub struct TelemetryView<'a> {
+    header: Header,
+    data: &'a [u8],
+}
+
+impl<'a> TelemetryView<'a> {
+    /// Wraps a whole datagram, only the header is decoded
+    /// Fails with `ConversionError` if it isn't a Car Telemetry packet
+    pub fn new(datagram: &'a [u8]) -> Result<Self, F1Error> {
+        let mut buf = datagram;
+        let header = parse_header(&mut buf)?;
+
+        if header.packet_id != PacketId::CarTelemetry {
+            return Err(F1Error::ConversionError);
+        }
+
+        if buf.len() < CAR_TELEMETRY_SIZE {
+            return Err(F1Error::IncompleteData);
+        }
+
+        Ok(TelemetryView {
+            header,
+            data: &buf[..CAR_TELEMETRY_SIZE],
+        })
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns `None` if the index is out of range
+    pub fn car(&self, index: usize) -> Option<CarTelemetryView<'a>> {
+        if index >= NUMBER_OF_CARS {
+            return None;
+        }
+
+        let start = index * CAR_TELEMETRY_DATA_SIZE;
+
+        Some(CarTelemetryView {
+            data: &self.data[start..start + CAR_TELEMETRY_DATA_SIZE],
+        })
+    }
+
+    pub fn player_car(&self) -> Option<CarTelemetryView<'a>> {
+        self.car(self.header.player_car_index as usize)
+    }
+
+    pub fn cars(&self) -> impl Iterator<Item = CarTelemetryView<'a>> {
+        self.data[..CAR_TELEMETRY_DATA_END]
+            .chunks_exact(CAR_TELEMETRY_DATA_SIZE)
+            .map(|data| CarTelemetryView { data })
+    }
+
+    pub fn mfd_panel_index(&self) -> u8 {
+        self.data[CAR_TELEMETRY_DATA_END]
+    }
+
+    pub fn mfd_panel_index_secondary(&self) -> u8 {
+        self.data[CAR_TELEMETRY_DATA_END + 1]
+    }
+
+    pub fn suggested_gear(&self) -> i8 {
+        self.data[CAR_TELEMETRY_DATA_END + 2] as i8
+    }
+
+    /// Decodes the whole packet, same as `parse_car_telemetry_packet`
+    pub fn decode(&self) -> TelemetryData {
+        parse_car_telemetry_packet(&mut &self.data[..])
+            .expect("size is checked in TelemetryView::new")
+    }
+}
+
+/// Telemetry of a single car, borrowed from a `TelemetryView`
+#[derive(Debug, Clone, Copy)]
+pub struct CarTelemetryView<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CarTelemetryView<'a> {
+    pub fn speed(&self) -> u16 {
+        (&self.data[0..]).get_u16_le()
+    }
+
+    pub fn throttle(&self) -> f32 {
+        (&self.data[2..]).get_f32_le()
+    }
+
+    pub fn steer(&self) -> f32 {
+        (&self.data[6..]).get_f32_le()
+    }
+
+    pub fn brake(&self) -> f32 {
+        (&self.data[10..]).get_f32_le()
+    }
+
+    pub fn clutch(&self) -> u8 {
+        self.data[14]
+    }
+
+    pub fn gear(&self) -> i8 {
+        self.data[15] as i8
+    }
+
+    pub fn engine_rpm(&self) -> u16 {
+        (&self.data[16..]).get_u16_le()
+    }
+
+    pub fn drs(&self) -> bool {
+        self.data[18] == 1
+    }
+
+    pub fn rev_lights_percent(&self) -> u8 {
+        self.data[19]
+    }
+
+    pub fn rev_lights_bit(&self) -> u16 {
+        (&self.data[20..]).get_u16_le()
+    }
+
+    pub fn brakes_temp(&self) -> WheelsData<u16> {
+        parse_wheels_data_u16(&mut &self.data[22..])
+    }
+
+    pub fn tyres_surface_temp(&self) -> WheelsData<u8> {
+        parse_wheels_data_u8(&mut &self.data[30..])
+    }
+
+    pub fn tyres_inner_temp(&self) -> WheelsData<u8> {
+        parse_wheels_data_u8(&mut &self.data[34..])
+    }
+
+    pub fn engine_temp(&self) -> u16 {
+        (&self.data[38..]).get_u16_le()
+    }
+
+    pub fn tyres_pressure(&self) -> WheelsData<f32> {
+        parse_wheels_data_f32(&mut &self.data[40..])
+    }
+
+    pub fn surface_type(&self) -> WheelsData<SurfaceType> {
+        parse_wheels_data_u8(&mut &self.data[56..]).map(SurfaceType::from)
+    }
+
+    pub fn decode(&self) -> CarTelemetryData {
+        CarTelemetryData {
+            speed: self.speed(),
+            throttle: self.throttle(),
+            steer: self.steer(),
+            brake: self.brake(),
+            clutch: self.clutch(),
+            gear: self.gear(),
+            engine_rpm: self.engine_rpm(),
+            drs: self.drs(),
+            rev_lights_percent: self.rev_lights_percent(),
+            rev_lights_bit: self.rev_lights_bit(),
+            brakes_temp: self.brakes_temp(),
+            tyres_surface_temp: self.tyres_surface_temp(),
+            tyres_inner_temp: self.tyres_inner_temp(),
+            engine_temp: self.engine_temp(),
+            tyres_pressure: self.tyres_pressure(),
+            surface_type: self.surface_type(),
+        }
+    }
+}
+
+/// Driving surface under a wheel, as listed in the game's appendix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SurfaceType {
+    #[default]
+    Tarmac,
+    RumbleStrip,
+    Concrete,
+    Rock,
+    Gravel,
+    Mud,
+    Sand,
+    Grass,
+    Water,
+    Cobblestone,
+    Metal,
+    Ridged,
+    /// Value not listed in the game's appendix
+    Unknown(u8),
+}
+
+impl SurfaceType {
+    /// Loose or natural surfaces outside the track, kerbs and ridged run-offs are on track
+    pub fn is_off_track(&self) -> bool {
+        matches!(
+            self,
+            SurfaceType::Rock
+                | SurfaceType::Gravel
+                | SurfaceType::Mud
+                | SurfaceType::Sand
+                | SurfaceType::Grass
+                | SurfaceType::Water
+        )
+    }
+
+    pub fn is_kerb(&self) -> bool {
+        matches!(self, SurfaceType::RumbleStrip | SurfaceType::Ridged)
+    }
+}
+
+impl From<u8> for SurfaceType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => SurfaceType::Tarmac,
+            1 => SurfaceType::RumbleStrip,
+            2 => SurfaceType::Concrete,
+            3 => SurfaceType::Rock,
+            4 => SurfaceType::Gravel,
+            5 => SurfaceType::Mud,
+            6 => SurfaceType::Sand,
+            7 => SurfaceType::Grass,
+            8 => SurfaceType::Water,
+            9 => SurfaceType::Cobblestone,
+            10 => SurfaceType::Metal,
+            11 => SurfaceType::Ridged,
+            value => SurfaceType::Unknown(value),
+        }
+    }
+}
+
+impl From<SurfaceType> for u8 {
+    fn from(value: SurfaceType) -> Self {
+        match value {
+            SurfaceType::Tarmac => 0,
+            SurfaceType::RumbleStrip => 1,
+            SurfaceType::Concrete => 2,
+            SurfaceType::Rock => 3,
+            SurfaceType::Gravel => 4,
+            SurfaceType::Mud => 5,
+            SurfaceType::Sand => 6,
+            SurfaceType::Grass => 7,
+            SurfaceType::Water => 8,
+            SurfaceType::Cobblestone => 9,
+            SurfaceType::Metal => 10,
+            SurfaceType::Ridged => 11,
+            SurfaceType::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for SurfaceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SurfaceType::Tarmac => f.write_str("Tarmac"),
+            SurfaceType::RumbleStrip => f.write_str("Rumble strip"),
+            SurfaceType::Concrete => f.write_str("Concrete"),
+            SurfaceType::Rock => f.write_str("Rock"),
+            SurfaceType::Gravel => f.write_str("Gravel"),
+            SurfaceType::Mud => f.write_str("Mud"),
+            SurfaceType::Sand => f.write_str("Sand"),
+            SurfaceType::Grass => f.write_str("Grass"),
+            SurfaceType::Water => f.write_str("Water"),
+            SurfaceType::Cobblestone => f.write_str("Cobblestone"),
+            SurfaceType::Metal => f.write_str("Metal"),
+            SurfaceType::Ridged => f.write_str("Ridged"),
+            SurfaceType::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl WheelsData<SurfaceType> {
+    /// Number of wheels on an off track surface
+    pub fn wheels_off_track(&self) -> usize {
+        self.iter().filter(|surface| surface.is_off_track()).count()
+    }
+
+    /// All four wheels are off track, the usual track limits rule
+    pub fn is_off_track(&self) -> bool {
+        self.wheels_off_track() == 4
+    }
+
+    /// Number of wheels on a kerb
+    pub fn wheels_on_kerb(&self) -> usize {
+        self.iter().filter(|surface| surface.is_kerb()).count()
+    }
 }
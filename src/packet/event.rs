@@ -1,13 +1,18 @@
-use std::{collections::HashSet, io::Cursor};
+use alloc::string::String;
+use core::fmt;
 
-use bytes::{Buf, BytesMut};
+use bytes::{Buf, BufMut, BytesMut};
 use enum_iterator::IntoEnumIterator;
 
 use crate::error::F1Error;
+use crate::packet;
 
 const EVENT_CODE_SIZE: usize = 4;
 
-pub fn parse_event_packet(buf: &mut Cursor<&mut BytesMut>) -> Result<EventData, F1Error> {
+/// The game always sends the size of the largest event details
+pub const EVENT_PACKET_SIZE: usize = 36 - packet::header::HEADER_SIZE;
+
+pub fn parse_event_packet(buf: &mut impl Buf) -> Result<EventData, F1Error> {
     use EventDataDetails::*;
 
     if buf.remaining() < EVENT_CODE_SIZE {
@@ -42,7 +47,7 @@ pub fn parse_event_packet(buf: &mut Cursor<&mut BytesMut>) -> Result<EventData,
     })
 }
 
-fn parse_fastest_lap(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_fastest_lap(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 5 {
         return Err(F1Error::IncompleteData);
     }
@@ -53,7 +58,7 @@ fn parse_fastest_lap(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails
     })
 }
 
-fn parse_retirement(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_retirement(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 1 {
         return Err(F1Error::IncompleteData);
     }
@@ -63,7 +68,7 @@ fn parse_retirement(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails,
     })
 }
 
-fn parse_teammate_in_pits(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_teammate_in_pits(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 1 {
         return Err(F1Error::IncompleteData);
     }
@@ -73,7 +78,7 @@ fn parse_teammate_in_pits(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDe
     })
 }
 
-fn parse_race_winner(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_race_winner(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 1 {
         return Err(F1Error::IncompleteData);
     }
@@ -83,14 +88,14 @@ fn parse_race_winner(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails
     })
 }
 
-fn parse_penalty(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_penalty(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 7 {
         return Err(F1Error::IncompleteData);
     }
 
     Ok(EventDataDetails::Penalty {
-        penalty_type: buf.get_u8(),
-        infringement_type: buf.get_u8(),
+        penalty_type: buf.get_u8().into(),
+        infringement_type: buf.get_u8().into(),
         vehicle_idx: buf.get_u8(),
         other_vehicle_idx: buf.get_u8(),
         time: buf.get_u8(),
@@ -99,7 +104,7 @@ fn parse_penalty(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1
     })
 }
 
-fn parse_speed_trap(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_speed_trap(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 7 {
         return Err(F1Error::IncompleteData);
     }
@@ -112,7 +117,7 @@ fn parse_speed_trap(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails,
     })
 }
 
-fn parse_start_lights(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_start_lights(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 1 {
         return Err(F1Error::IncompleteData);
     }
@@ -122,7 +127,7 @@ fn parse_start_lights(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetail
     })
 }
 
-fn parse_drive_penalty(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_drive_penalty(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 1 {
         return Err(F1Error::IncompleteData);
     }
@@ -132,7 +137,7 @@ fn parse_drive_penalty(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetai
     })
 }
 
-fn parse_stop_go_penalty(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_stop_go_penalty(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 1 {
         return Err(F1Error::IncompleteData);
     }
@@ -142,7 +147,7 @@ fn parse_stop_go_penalty(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDet
     })
 }
 
-fn parse_flashback(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_flashback(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 8 {
         return Err(F1Error::IncompleteData);
     }
@@ -153,40 +158,97 @@ fn parse_flashback(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails,
     })
 }
 
-fn parse_buttons(buf: &mut Cursor<&mut BytesMut>) -> Result<EventDataDetails, F1Error> {
+fn parse_buttons(buf: &mut impl Buf) -> Result<EventDataDetails, F1Error> {
     if buf.remaining() < 4 {
         return Err(F1Error::IncompleteData);
     }
 
-    let flags = buf.get_u32_le();
+    Ok(EventDataDetails::Buttons {
+        button_status: ButtonSet::from_bits(buf.get_u32_le()),
+    })
+}
 
-    let mut set = HashSet::new();
+fn parse_event_code(buf: &mut impl Buf) -> Result<EventCode, F1Error> {
+    let code_chars = [buf.get_u8(), buf.get_u8(), buf.get_u8(), buf.get_u8()];
+    let code_string = String::from(core::str::from_utf8(&code_chars)?);
 
-    for button in ButtonFlags::into_enum_iter() {
-        let bit = button as u32;
+    code_string.try_into()
+}
 
-        if flags & bit != 0 {
-            set.insert(button);
-        }
-    }
+pub fn write_event_packet(data: &EventData, buf: &mut BytesMut) {
+    use EventDataDetails::*;
 
-    Ok(EventDataDetails::Buttons { button_status: set })
-}
+    let start = buf.len();
 
-fn parse_event_code(buf: &mut Cursor<&mut BytesMut>) -> Result<EventCode, F1Error> {
-    let code_chars = [buf.get_u8(), buf.get_u8(), buf.get_u8(), buf.get_u8()];
-    let code_string = std::str::from_utf8(&code_chars)?.to_string();
+    buf.put_slice(data.event_string_code.as_str().as_bytes());
 
-    code_string.try_into()
+    match &data.event_details {
+        SessionStarted | SessionEnded | DRSEnabled | DRSDisabled | ChequeredFlag | LightsOut => {}
+        FastestLap {
+            vehicle_idx,
+            lap_time,
+        } => {
+            buf.put_u8(*vehicle_idx);
+            buf.put_f32_le(*lap_time);
+        }
+        Retirement { vehicle_idx }
+        | TeamMateInPits { vehicle_idx }
+        | RaceWinner { vehicle_idx }
+        | DriveThroughPenaltyServed { vehicle_idx }
+        | StopGoPenaltyServed { vehicle_idx } => buf.put_u8(*vehicle_idx),
+        Penalty {
+            penalty_type,
+            infringement_type,
+            vehicle_idx,
+            other_vehicle_idx,
+            time,
+            lap_num,
+            places_gained,
+        } => {
+            buf.put_u8((*penalty_type).into());
+            buf.put_u8((*infringement_type).into());
+            buf.put_u8(*vehicle_idx);
+            buf.put_u8(*other_vehicle_idx);
+            buf.put_u8(*time);
+            buf.put_u8(*lap_num);
+            buf.put_u8(*places_gained);
+        }
+        SpeedTrap {
+            vehicle_idx,
+            speed,
+            overall_fastest_in_session,
+            driver_fastest_in_session,
+        } => {
+            buf.put_u8(*vehicle_idx);
+            buf.put_f32_le(*speed);
+            buf.put_u8(*overall_fastest_in_session);
+            buf.put_u8(*driver_fastest_in_session);
+        }
+        StartLights { num_lights } => buf.put_u8(*num_lights),
+        Flashback {
+            flashback_frame_identifier,
+            flashback_session_time,
+        } => {
+            buf.put_u32_le(*flashback_frame_identifier);
+            buf.put_f32_le(*flashback_session_time);
+        }
+        Buttons { button_status } => buf.put_u32_le(button_status.bits()),
+    }
+
+    let written = buf.len() - start;
+    buf.put_bytes(0, EVENT_PACKET_SIZE - written);
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EventData {
     pub event_string_code: EventCode,
     pub event_details: EventDataDetails,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum EventDataDetails {
     SessionStarted,
     SessionEnded,
@@ -207,8 +269,8 @@ pub enum EventDataDetails {
         vehicle_idx: u8,
     },
     Penalty {
-        penalty_type: u8,
-        infringement_type: u8,
+        penalty_type: PenaltyType,
+        infringement_type: InfringementType,
         vehicle_idx: u8,
         other_vehicle_idx: u8,
         time: u8,
@@ -240,11 +302,12 @@ pub enum EventDataDetails {
     },
 
     Buttons {
-        button_status: std::collections::HashSet<ButtonFlags>,
+        button_status: ButtonSet,
     },
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventCode {
     SessionStarted,
     SessionEnded,
@@ -265,6 +328,31 @@ pub enum EventCode {
     ButtonStatus,
 }
 
+impl EventCode {
+    /// The four character code used on the wire
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventCode::SessionStarted => "SSTA",
+            EventCode::SessionEnded => "SEND",
+            EventCode::FastestLap => "FTLP",
+            EventCode::Retirement => "RTMT",
+            EventCode::DRSEnabled => "DRSE",
+            EventCode::DRSDisabled => "DRSD",
+            EventCode::TeamMateInPits => "TMPT",
+            EventCode::ChequeredFlag => "CHQF",
+            EventCode::RaceWinner => "RCWN",
+            EventCode::PenaltyIssued => "PENA",
+            EventCode::SpeedTrapTriggered => "SPTP",
+            EventCode::StartLights => "STLG",
+            EventCode::LightsOut => "LGOT",
+            EventCode::DriveThroughServed => "DTSV",
+            EventCode::StopGoServed => "SGSV",
+            EventCode::Flashback => "FLBK",
+            EventCode::ButtonStatus => "BUTN",
+        }
+    }
+}
+
 impl TryFrom<String> for EventCode {
     type Error = F1Error;
 
@@ -293,7 +381,8 @@ impl TryFrom<String> for EventCode {
 }
 
 #[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, IntoEnumIterator, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, IntoEnumIterator, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ButtonFlags {
     A = 0x00000001,
     Y = 0x00000002,
@@ -316,3 +405,494 @@ pub enum ButtonFlags {
     RightStickDown = 0x00040000,
     Special = 0x00080000,
 }
+
+/// Set of pressed buttons, stored as the raw bit flags sent by the game
+/// Bits without a matching `ButtonFlags` are kept, so the value is written back unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ButtonSet(u32);
+
+impl ButtonSet {
+    pub const fn empty() -> Self {
+        ButtonSet(0)
+    }
+
+    pub const fn from_bits(bits: u32) -> Self {
+        ButtonSet(bits)
+    }
+
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, button: ButtonFlags) -> bool {
+        self.0 & button as u32 != 0
+    }
+
+    pub fn insert(&mut self, button: ButtonFlags) {
+        self.0 |= button as u32;
+    }
+
+    pub fn remove(&mut self, button: ButtonFlags) {
+        self.0 &= !(button as u32);
+    }
+
+    pub const fn union(&self, other: &ButtonSet) -> ButtonSet {
+        ButtonSet(self.0 | other.0)
+    }
+
+    pub const fn intersection(&self, other: &ButtonSet) -> ButtonSet {
+        ButtonSet(self.0 & other.0)
+    }
+
+    /// Buttons in this set that aren't in `other`
+    pub const fn difference(&self, other: &ButtonSet) -> ButtonSet {
+        ButtonSet(self.0 & !other.0)
+    }
+
+    /// Buttons that are pressed now but weren't in the previous status
+    pub const fn pressed_since(&self, previous: &ButtonSet) -> ButtonSet {
+        self.difference(previous)
+    }
+
+    /// Buttons that were pressed in the previous status but aren't anymore
+    pub const fn released_since(&self, previous: &ButtonSet) -> ButtonSet {
+        previous.difference(self)
+    }
+
+    /// Iterates over the known buttons in the set, in `ButtonFlags` order
+    pub fn iter(&self) -> impl Iterator<Item = ButtonFlags> {
+        let set = *self;
+
+        ButtonFlags::into_enum_iter().filter(move |button| set.contains(*button))
+    }
+}
+
+impl From<ButtonFlags> for ButtonSet {
+    fn from(button: ButtonFlags) -> Self {
+        ButtonSet(button as u32)
+    }
+}
+
+impl FromIterator<ButtonFlags> for ButtonSet {
+    fn from_iter<I: IntoIterator<Item = ButtonFlags>>(iter: I) -> Self {
+        let mut set = ButtonSet::empty();
+
+        for button in iter {
+            set.insert(button);
+        }
+
+        set
+    }
+}
+
+/// Type of penalty issued, as listed in the game's appendix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PenaltyType {
+    DriveThrough,
+    StopGo,
+    GridPenalty,
+    PenaltyReminder,
+    TimePenalty,
+    Warning,
+    Disqualified,
+    RemovedFromFormationLap,
+    ParkedTooLongTimer,
+    TyreRegulations,
+    ThisLapInvalidated,
+    ThisAndNextLapInvalidated,
+    ThisLapInvalidatedWithoutReason,
+    ThisAndNextLapInvalidatedWithoutReason,
+    ThisAndPreviousLapInvalidated,
+    ThisAndPreviousLapInvalidatedWithoutReason,
+    Retired,
+    BlackFlagTimer,
+    /// Value not listed in the game's appendix
+    Unknown(u8),
+}
+
+impl From<u8> for PenaltyType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => PenaltyType::DriveThrough,
+            1 => PenaltyType::StopGo,
+            2 => PenaltyType::GridPenalty,
+            3 => PenaltyType::PenaltyReminder,
+            4 => PenaltyType::TimePenalty,
+            5 => PenaltyType::Warning,
+            6 => PenaltyType::Disqualified,
+            7 => PenaltyType::RemovedFromFormationLap,
+            8 => PenaltyType::ParkedTooLongTimer,
+            9 => PenaltyType::TyreRegulations,
+            10 => PenaltyType::ThisLapInvalidated,
+            11 => PenaltyType::ThisAndNextLapInvalidated,
+            12 => PenaltyType::ThisLapInvalidatedWithoutReason,
+            13 => PenaltyType::ThisAndNextLapInvalidatedWithoutReason,
+            14 => PenaltyType::ThisAndPreviousLapInvalidated,
+            15 => PenaltyType::ThisAndPreviousLapInvalidatedWithoutReason,
+            16 => PenaltyType::Retired,
+            17 => PenaltyType::BlackFlagTimer,
+            value => PenaltyType::Unknown(value),
+        }
+    }
+}
+
+impl From<PenaltyType> for u8 {
+    fn from(value: PenaltyType) -> Self {
+        match value {
+            PenaltyType::DriveThrough => 0,
+            PenaltyType::StopGo => 1,
+            PenaltyType::GridPenalty => 2,
+            PenaltyType::PenaltyReminder => 3,
+            PenaltyType::TimePenalty => 4,
+            PenaltyType::Warning => 5,
+            PenaltyType::Disqualified => 6,
+            PenaltyType::RemovedFromFormationLap => 7,
+            PenaltyType::ParkedTooLongTimer => 8,
+            PenaltyType::TyreRegulations => 9,
+            PenaltyType::ThisLapInvalidated => 10,
+            PenaltyType::ThisAndNextLapInvalidated => 11,
+            PenaltyType::ThisLapInvalidatedWithoutReason => 12,
+            PenaltyType::ThisAndNextLapInvalidatedWithoutReason => 13,
+            PenaltyType::ThisAndPreviousLapInvalidated => 14,
+            PenaltyType::ThisAndPreviousLapInvalidatedWithoutReason => 15,
+            PenaltyType::Retired => 16,
+            PenaltyType::BlackFlagTimer => 17,
+            PenaltyType::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for PenaltyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PenaltyType::DriveThrough => f.write_str("Drive through"),
+            PenaltyType::StopGo => f.write_str("Stop go"),
+            PenaltyType::GridPenalty => f.write_str("Grid penalty"),
+            PenaltyType::PenaltyReminder => f.write_str("Penalty reminder"),
+            PenaltyType::TimePenalty => f.write_str("Time penalty"),
+            PenaltyType::Warning => f.write_str("Warning"),
+            PenaltyType::Disqualified => f.write_str("Disqualified"),
+            PenaltyType::RemovedFromFormationLap => f.write_str("Removed from formation lap"),
+            PenaltyType::ParkedTooLongTimer => f.write_str("Parked too long timer"),
+            PenaltyType::TyreRegulations => f.write_str("Tyre regulations"),
+            PenaltyType::ThisLapInvalidated => f.write_str("This lap invalidated"),
+            PenaltyType::ThisAndNextLapInvalidated => f.write_str("This and next lap invalidated"),
+            PenaltyType::ThisLapInvalidatedWithoutReason => {
+                f.write_str("This lap invalidated without reason")
+            }
+            PenaltyType::ThisAndNextLapInvalidatedWithoutReason => {
+                f.write_str("This and next lap invalidated without reason")
+            }
+            PenaltyType::ThisAndPreviousLapInvalidated => {
+                f.write_str("This and previous lap invalidated")
+            }
+            PenaltyType::ThisAndPreviousLapInvalidatedWithoutReason => {
+                f.write_str("This and previous lap invalidated without reason")
+            }
+            PenaltyType::Retired => f.write_str("Retired"),
+            PenaltyType::BlackFlagTimer => f.write_str("Black flag timer"),
+            PenaltyType::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+/// Reason a penalty was issued, as listed in the game's appendix
+/// Values 52 and 53 aren't used by the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InfringementType {
+    BlockingBySlowDriving,
+    BlockingByWrongWayDriving,
+    ReversingOffTheStartLine,
+    BigCollision,
+    SmallCollision,
+    CollisionFailedToHandBackPositionSingle,
+    CollisionFailedToHandBackPositionMultiple,
+    CornerCuttingGainedTime,
+    CornerCuttingOvertakeSingle,
+    CornerCuttingOvertakeMultiple,
+    CrossedPitExitLane,
+    IgnoringBlueFlags,
+    IgnoringYellowFlags,
+    IgnoringDriveThrough,
+    TooManyDriveThroughs,
+    DriveThroughReminderServeWithinNLaps,
+    DriveThroughReminderServeThisLap,
+    PitLaneSpeeding,
+    ParkedForTooLong,
+    IgnoringTyreRegulations,
+    TooManyPenalties,
+    MultipleWarnings,
+    ApproachingDisqualification,
+    TyreRegulationsSelectSingle,
+    TyreRegulationsSelectMultiple,
+    LapInvalidatedCornerCutting,
+    LapInvalidatedRunningWide,
+    CornerCuttingRanWideGainedTimeMinor,
+    CornerCuttingRanWideGainedTimeSignificant,
+    CornerCuttingRanWideGainedTimeExtreme,
+    LapInvalidatedWallRiding,
+    LapInvalidatedFlashbackUsed,
+    LapInvalidatedResetToTrack,
+    BlockingThePitlane,
+    JumpStart,
+    SafetyCarToCarCollision,
+    SafetyCarIllegalOvertake,
+    SafetyCarExceedingAllowedPace,
+    VirtualSafetyCarExceedingAllowedPace,
+    FormationLapBelowAllowedSpeed,
+    RetiredMechanicalFailure,
+    RetiredTerminallyDamaged,
+    SafetyCarFallingTooFarBack,
+    BlackFlagTimer,
+    UnservedStopGoPenalty,
+    UnservedDriveThroughPenalty,
+    EngineComponentChange,
+    GearboxChange,
+    LeagueGridPenalty,
+    RetryPenalty,
+    IllegalTimeGain,
+    MandatoryPitstop,
+    AttributeAssigned,
+    /// Value not listed in the game's appendix
+    Unknown(u8),
+}
+
+impl From<u8> for InfringementType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => InfringementType::BlockingBySlowDriving,
+            1 => InfringementType::BlockingByWrongWayDriving,
+            2 => InfringementType::ReversingOffTheStartLine,
+            3 => InfringementType::BigCollision,
+            4 => InfringementType::SmallCollision,
+            5 => InfringementType::CollisionFailedToHandBackPositionSingle,
+            6 => InfringementType::CollisionFailedToHandBackPositionMultiple,
+            7 => InfringementType::CornerCuttingGainedTime,
+            8 => InfringementType::CornerCuttingOvertakeSingle,
+            9 => InfringementType::CornerCuttingOvertakeMultiple,
+            10 => InfringementType::CrossedPitExitLane,
+            11 => InfringementType::IgnoringBlueFlags,
+            12 => InfringementType::IgnoringYellowFlags,
+            13 => InfringementType::IgnoringDriveThrough,
+            14 => InfringementType::TooManyDriveThroughs,
+            15 => InfringementType::DriveThroughReminderServeWithinNLaps,
+            16 => InfringementType::DriveThroughReminderServeThisLap,
+            17 => InfringementType::PitLaneSpeeding,
+            18 => InfringementType::ParkedForTooLong,
+            19 => InfringementType::IgnoringTyreRegulations,
+            20 => InfringementType::TooManyPenalties,
+            21 => InfringementType::MultipleWarnings,
+            22 => InfringementType::ApproachingDisqualification,
+            23 => InfringementType::TyreRegulationsSelectSingle,
+            24 => InfringementType::TyreRegulationsSelectMultiple,
+            25 => InfringementType::LapInvalidatedCornerCutting,
+            26 => InfringementType::LapInvalidatedRunningWide,
+            27 => InfringementType::CornerCuttingRanWideGainedTimeMinor,
+            28 => InfringementType::CornerCuttingRanWideGainedTimeSignificant,
+            29 => InfringementType::CornerCuttingRanWideGainedTimeExtreme,
+            30 => InfringementType::LapInvalidatedWallRiding,
+            31 => InfringementType::LapInvalidatedFlashbackUsed,
+            32 => InfringementType::LapInvalidatedResetToTrack,
+            33 => InfringementType::BlockingThePitlane,
+            34 => InfringementType::JumpStart,
+            35 => InfringementType::SafetyCarToCarCollision,
+            36 => InfringementType::SafetyCarIllegalOvertake,
+            37 => InfringementType::SafetyCarExceedingAllowedPace,
+            38 => InfringementType::VirtualSafetyCarExceedingAllowedPace,
+            39 => InfringementType::FormationLapBelowAllowedSpeed,
+            40 => InfringementType::RetiredMechanicalFailure,
+            41 => InfringementType::RetiredTerminallyDamaged,
+            42 => InfringementType::SafetyCarFallingTooFarBack,
+            43 => InfringementType::BlackFlagTimer,
+            44 => InfringementType::UnservedStopGoPenalty,
+            45 => InfringementType::UnservedDriveThroughPenalty,
+            46 => InfringementType::EngineComponentChange,
+            47 => InfringementType::GearboxChange,
+            48 => InfringementType::LeagueGridPenalty,
+            49 => InfringementType::RetryPenalty,
+            50 => InfringementType::IllegalTimeGain,
+            51 => InfringementType::MandatoryPitstop,
+            54 => InfringementType::AttributeAssigned,
+            value => InfringementType::Unknown(value),
+        }
+    }
+}
+
+impl From<InfringementType> for u8 {
+    fn from(value: InfringementType) -> Self {
+        match value {
+            InfringementType::BlockingBySlowDriving => 0,
+            InfringementType::BlockingByWrongWayDriving => 1,
+            InfringementType::ReversingOffTheStartLine => 2,
+            InfringementType::BigCollision => 3,
+            InfringementType::SmallCollision => 4,
+            InfringementType::CollisionFailedToHandBackPositionSingle => 5,
+            InfringementType::CollisionFailedToHandBackPositionMultiple => 6,
+            InfringementType::CornerCuttingGainedTime => 7,
+            InfringementType::CornerCuttingOvertakeSingle => 8,
+            InfringementType::CornerCuttingOvertakeMultiple => 9,
+            InfringementType::CrossedPitExitLane => 10,
+            InfringementType::IgnoringBlueFlags => 11,
+            InfringementType::IgnoringYellowFlags => 12,
+            InfringementType::IgnoringDriveThrough => 13,
+            InfringementType::TooManyDriveThroughs => 14,
+            InfringementType::DriveThroughReminderServeWithinNLaps => 15,
+            InfringementType::DriveThroughReminderServeThisLap => 16,
+            InfringementType::PitLaneSpeeding => 17,
+            InfringementType::ParkedForTooLong => 18,
+            InfringementType::IgnoringTyreRegulations => 19,
+            InfringementType::TooManyPenalties => 20,
+            InfringementType::MultipleWarnings => 21,
+            InfringementType::ApproachingDisqualification => 22,
+            InfringementType::TyreRegulationsSelectSingle => 23,
+            InfringementType::TyreRegulationsSelectMultiple => 24,
+            InfringementType::LapInvalidatedCornerCutting => 25,
+            InfringementType::LapInvalidatedRunningWide => 26,
+            InfringementType::CornerCuttingRanWideGainedTimeMinor => 27,
+            InfringementType::CornerCuttingRanWideGainedTimeSignificant => 28,
+            InfringementType::CornerCuttingRanWideGainedTimeExtreme => 29,
+            InfringementType::LapInvalidatedWallRiding => 30,
+            InfringementType::LapInvalidatedFlashbackUsed => 31,
+            InfringementType::LapInvalidatedResetToTrack => 32,
+            InfringementType::BlockingThePitlane => 33,
+            InfringementType::JumpStart => 34,
+            InfringementType::SafetyCarToCarCollision => 35,
+            InfringementType::SafetyCarIllegalOvertake => 36,
+            InfringementType::SafetyCarExceedingAllowedPace => 37,
+            InfringementType::VirtualSafetyCarExceedingAllowedPace => 38,
+            InfringementType::FormationLapBelowAllowedSpeed => 39,
+            InfringementType::RetiredMechanicalFailure => 40,
+            InfringementType::RetiredTerminallyDamaged => 41,
+            InfringementType::SafetyCarFallingTooFarBack => 42,
+            InfringementType::BlackFlagTimer => 43,
+            InfringementType::UnservedStopGoPenalty => 44,
+            InfringementType::UnservedDriveThroughPenalty => 45,
+            InfringementType::EngineComponentChange => 46,
+            InfringementType::GearboxChange => 47,
+            InfringementType::LeagueGridPenalty => 48,
+            InfringementType::RetryPenalty => 49,
+            InfringementType::IllegalTimeGain => 50,
+            InfringementType::MandatoryPitstop => 51,
+            InfringementType::AttributeAssigned => 54,
+            InfringementType::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for InfringementType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InfringementType::BlockingBySlowDriving => f.write_str("Blocking by slow driving"),
+            InfringementType::BlockingByWrongWayDriving => {
+                f.write_str("Blocking by wrong way driving")
+            }
+            InfringementType::ReversingOffTheStartLine => {
+                f.write_str("Reversing off the start line")
+            }
+            InfringementType::BigCollision => f.write_str("Big Collision"),
+            InfringementType::SmallCollision => f.write_str("Small Collision"),
+            InfringementType::CollisionFailedToHandBackPositionSingle => {
+                f.write_str("Collision failed to hand back position single")
+            }
+            InfringementType::CollisionFailedToHandBackPositionMultiple => {
+                f.write_str("Collision failed to hand back position multiple")
+            }
+            InfringementType::CornerCuttingGainedTime => f.write_str("Corner cutting gained time"),
+            InfringementType::CornerCuttingOvertakeSingle => {
+                f.write_str("Corner cutting overtake single")
+            }
+            InfringementType::CornerCuttingOvertakeMultiple => {
+                f.write_str("Corner cutting overtake multiple")
+            }
+            InfringementType::CrossedPitExitLane => f.write_str("Crossed pit exit lane"),
+            InfringementType::IgnoringBlueFlags => f.write_str("Ignoring blue flags"),
+            InfringementType::IgnoringYellowFlags => f.write_str("Ignoring yellow flags"),
+            InfringementType::IgnoringDriveThrough => f.write_str("Ignoring drive through"),
+            InfringementType::TooManyDriveThroughs => f.write_str("Too many drive throughs"),
+            InfringementType::DriveThroughReminderServeWithinNLaps => {
+                f.write_str("Drive through reminder serve within n laps")
+            }
+            InfringementType::DriveThroughReminderServeThisLap => {
+                f.write_str("Drive through reminder serve this lap")
+            }
+            InfringementType::PitLaneSpeeding => f.write_str("Pit lane speeding"),
+            InfringementType::ParkedForTooLong => f.write_str("Parked for too long"),
+            InfringementType::IgnoringTyreRegulations => f.write_str("Ignoring tyre regulations"),
+            InfringementType::TooManyPenalties => f.write_str("Too many penalties"),
+            InfringementType::MultipleWarnings => f.write_str("Multiple warnings"),
+            InfringementType::ApproachingDisqualification => {
+                f.write_str("Approaching disqualification")
+            }
+            InfringementType::TyreRegulationsSelectSingle => {
+                f.write_str("Tyre regulations select single")
+            }
+            InfringementType::TyreRegulationsSelectMultiple => {
+                f.write_str("Tyre regulations select multiple")
+            }
+            InfringementType::LapInvalidatedCornerCutting => {
+                f.write_str("Lap invalidated corner cutting")
+            }
+            InfringementType::LapInvalidatedRunningWide => {
+                f.write_str("Lap invalidated running wide")
+            }
+            InfringementType::CornerCuttingRanWideGainedTimeMinor => {
+                f.write_str("Corner cutting ran wide gained time minor")
+            }
+            InfringementType::CornerCuttingRanWideGainedTimeSignificant => {
+                f.write_str("Corner cutting ran wide gained time significant")
+            }
+            InfringementType::CornerCuttingRanWideGainedTimeExtreme => {
+                f.write_str("Corner cutting ran wide gained time extreme")
+            }
+            InfringementType::LapInvalidatedWallRiding => {
+                f.write_str("Lap invalidated wall riding")
+            }
+            InfringementType::LapInvalidatedFlashbackUsed => {
+                f.write_str("Lap invalidated flashback used")
+            }
+            InfringementType::LapInvalidatedResetToTrack => {
+                f.write_str("Lap invalidated reset to track")
+            }
+            InfringementType::BlockingThePitlane => f.write_str("Blocking the pitlane"),
+            InfringementType::JumpStart => f.write_str("Jump start"),
+            InfringementType::SafetyCarToCarCollision => f.write_str("Safety car to car collision"),
+            InfringementType::SafetyCarIllegalOvertake => {
+                f.write_str("Safety car illegal overtake")
+            }
+            InfringementType::SafetyCarExceedingAllowedPace => {
+                f.write_str("Safety car exceeding allowed pace")
+            }
+            InfringementType::VirtualSafetyCarExceedingAllowedPace => {
+                f.write_str("Virtual safety car exceeding allowed pace")
+            }
+            InfringementType::FormationLapBelowAllowedSpeed => {
+                f.write_str("Formation lap below allowed speed")
+            }
+            InfringementType::RetiredMechanicalFailure => f.write_str("Retired mechanical failure"),
+            InfringementType::RetiredTerminallyDamaged => f.write_str("Retired terminally damaged"),
+            InfringementType::SafetyCarFallingTooFarBack => {
+                f.write_str("Safety car falling too far back")
+            }
+            InfringementType::BlackFlagTimer => f.write_str("Black flag timer"),
+            InfringementType::UnservedStopGoPenalty => f.write_str("Unserved stop go penalty"),
+            InfringementType::UnservedDriveThroughPenalty => {
+                f.write_str("Unserved drive through penalty")
+            }
+            InfringementType::EngineComponentChange => f.write_str("Engine component change"),
+            InfringementType::GearboxChange => f.write_str("Gearbox change"),
+            InfringementType::LeagueGridPenalty => f.write_str("League grid penalty"),
+            InfringementType::RetryPenalty => f.write_str("Retry penalty"),
+            InfringementType::IllegalTimeGain => f.write_str("Illegal time gain"),
+            InfringementType::MandatoryPitstop => f.write_str("Mandatory pitstop"),
+            InfringementType::AttributeAssigned => f.write_str("Attribute assigned"),
+            InfringementType::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
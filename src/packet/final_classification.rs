@@ -0,0 +1,111 @@
+#![allow(clippy::needless_range_loop)]
+
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::utils::*;
+
+pub const FINAL_CLASSIFICATION_PACKET_SIZE: usize = 839 - packet::header::HEADER_SIZE;
+
+pub fn parse_final_classification_packet(
+    buf: &mut impl Buf,
+) -> Result<FinalClassificationData, F1Error> {
+    if buf.remaining() < FINAL_CLASSIFICATION_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let num_cars = buf.get_u8();
+
+    let mut classification_data: [CarFinalClassificationData; NUMBER_OF_CARS] =
+        [CarFinalClassificationData::default(); NUMBER_OF_CARS];
+
+    for i in 0..NUMBER_OF_CARS {
+        let position = buf.get_u8();
+        let num_laps = buf.get_u8();
+        let grid_position = buf.get_u8();
+        let points = buf.get_u8();
+        let num_pit_stops = buf.get_u8();
+        let result_status = buf.get_u8();
+        let best_lap_time = buf.get_u32_le();
+        let total_race_time = buf.get_f64_le();
+        let penalties_time = buf.get_u8();
+        let num_penalties = buf.get_u8();
+        let num_tyre_stints = buf.get_u8();
+
+        let mut tyre_stints_actual = [0u8; MAX_TYRE_STINTS];
+        buf.copy_to_slice(&mut tyre_stints_actual);
+
+        let mut tyre_stints_visual = [0u8; MAX_TYRE_STINTS];
+        buf.copy_to_slice(&mut tyre_stints_visual);
+
+        classification_data[i] = CarFinalClassificationData {
+            position,
+            num_laps,
+            grid_position,
+            points,
+            num_pit_stops,
+            result_status,
+            best_lap_time,
+            total_race_time,
+            penalties_time,
+            num_penalties,
+            num_tyre_stints,
+            tyre_stints_actual,
+            tyre_stints_visual,
+        };
+    }
+
+    let packet = FinalClassificationData {
+        num_cars,
+        classification_data,
+    };
+
+    Ok(packet)
+}
+
+pub fn write_final_classification_packet(data: &FinalClassificationData, buf: &mut BytesMut) {
+    buf.put_u8(data.num_cars);
+
+    for car in data.classification_data.iter() {
+        buf.put_u8(car.position);
+        buf.put_u8(car.num_laps);
+        buf.put_u8(car.grid_position);
+        buf.put_u8(car.points);
+        buf.put_u8(car.num_pit_stops);
+        buf.put_u8(car.result_status);
+        buf.put_u32_le(car.best_lap_time);
+        buf.put_f64_le(car.total_race_time);
+        buf.put_u8(car.penalties_time);
+        buf.put_u8(car.num_penalties);
+        buf.put_u8(car.num_tyre_stints);
+        buf.put_slice(&car.tyre_stints_actual);
+        buf.put_slice(&car.tyre_stints_visual);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinalClassificationData {
+    pub num_cars: u8,
+    pub classification_data: [CarFinalClassificationData; NUMBER_OF_CARS],
+}
+
+/// Best lap time is in milliseconds, total race time is in seconds without penalties
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarFinalClassificationData {
+    pub position: u8,
+    pub num_laps: u8,
+    pub grid_position: u8,
+    pub points: u8,
+    pub num_pit_stops: u8,
+    pub result_status: u8,
+    pub best_lap_time: u32,
+    pub total_race_time: f64,
+    pub penalties_time: u8,
+    pub num_penalties: u8,
+    pub num_tyre_stints: u8,
+    pub tyre_stints_actual: [u8; MAX_TYRE_STINTS],
+    pub tyre_stints_visual: [u8; MAX_TYRE_STINTS],
+}
@@ -1,11 +1,10 @@
-use bytes::{Buf, BytesMut};
-use std::io::Cursor;
+use bytes::{Buf, BufMut, BytesMut};
 
 use crate::error::F1Error;
 
 pub const HEADER_SIZE: usize = 24;
 
-pub fn parse_header(buf: &mut Cursor<&mut BytesMut>) -> Result<Header, F1Error> {
+pub fn parse_header(buf: &mut impl Buf) -> Result<Header, F1Error> {
     if buf.remaining() < HEADER_SIZE {
         return Err(F1Error::IncompleteData);
     }
@@ -35,7 +34,21 @@ pub fn parse_header(buf: &mut Cursor<&mut BytesMut>) -> Result<Header, F1Error>
     Ok(header)
 }
 
+pub fn write_header(header: &Header, buf: &mut BytesMut) {
+    buf.put_u16_le(header.format);
+    buf.put_u8(header.version.0);
+    buf.put_u8(header.version.1);
+    buf.put_u8(header.packet_version);
+    buf.put_u8((&header.packet_id).into());
+    buf.put_u64_le(header.session_uid);
+    buf.put_f32_le(header.session_time);
+    buf.put_u32_le(header.frame_identifier);
+    buf.put_u8(header.player_car_index);
+    buf.put_u8(header.secondary_player_car_index);
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     pub format: u16,
     pub version: (u8, u8),
@@ -49,6 +62,7 @@ pub struct Header {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PacketId {
     Motion,
     Session,
@@ -85,3 +99,22 @@ impl TryFrom<u8> for PacketId {
         }
     }
 }
+
+impl From<&PacketId> for u8 {
+    fn from(value: &PacketId) -> Self {
+        match value {
+            PacketId::Motion => 0,
+            PacketId::Session => 1,
+            PacketId::LapData => 2,
+            PacketId::Event => 3,
+            PacketId::Participants => 4,
+            PacketId::CarSetups => 5,
+            PacketId::CarTelemetry => 6,
+            PacketId::CarStatus => 7,
+            PacketId::FinalClassification => 8,
+            PacketId::LobbyInfo => 9,
+            PacketId::CarDamage => 10,
+            PacketId::SessionHistory => 11,
+        }
+    }
+}
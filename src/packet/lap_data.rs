@@ -0,0 +1,138 @@
+#![allow(clippy::needless_range_loop)]
+
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::utils::*;
+
+pub const LAP_DATA_PACKET_SIZE: usize = 970 - packet::header::HEADER_SIZE;
+
+pub fn parse_lap_data_packet(buf: &mut impl Buf) -> Result<LapData, F1Error> {
+    if buf.remaining() < LAP_DATA_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let mut car_lap_data: [CarLapData; NUMBER_OF_CARS] = [CarLapData::default(); NUMBER_OF_CARS];
+
+    for i in 0..NUMBER_OF_CARS {
+        let last_lap_time = buf.get_u32_le();
+        let current_lap_time = buf.get_u32_le();
+        let sector1_time = buf.get_u16_le();
+        let sector2_time = buf.get_u16_le();
+        let lap_distance = buf.get_f32_le();
+        let total_distance = buf.get_f32_le();
+        let safety_car_delta = buf.get_f32_le();
+        let car_position = buf.get_u8();
+        let current_lap_num = buf.get_u8();
+        let pit_status = buf.get_u8();
+        let num_pit_stops = buf.get_u8();
+        let sector = buf.get_u8();
+        let current_lap_invalid = buf.get_u8() == 1;
+        let penalties = buf.get_u8();
+        let warnings = buf.get_u8();
+        let num_unserved_drive_through_pens = buf.get_u8();
+        let num_unserved_stop_go_pens = buf.get_u8();
+        let grid_position = buf.get_u8();
+        let driver_status = buf.get_u8();
+        let result_status = buf.get_u8();
+        let pit_lane_timer_active = buf.get_u8() == 1;
+        let pit_lane_time_in_lane = buf.get_u16_le();
+        let pit_stop_timer = buf.get_u16_le();
+        let pit_stop_should_serve_pen = buf.get_u8() == 1;
+
+        car_lap_data[i] = CarLapData {
+            last_lap_time,
+            current_lap_time,
+            sector1_time,
+            sector2_time,
+            lap_distance,
+            total_distance,
+            safety_car_delta,
+            car_position,
+            current_lap_num,
+            pit_status,
+            num_pit_stops,
+            sector,
+            current_lap_invalid,
+            penalties,
+            warnings,
+            num_unserved_drive_through_pens,
+            num_unserved_stop_go_pens,
+            grid_position,
+            driver_status,
+            result_status,
+            pit_lane_timer_active,
+            pit_lane_time_in_lane,
+            pit_stop_timer,
+            pit_stop_should_serve_pen,
+        };
+    }
+
+    Ok(LapData { car_lap_data })
+}
+
+pub fn write_lap_data_packet(data: &LapData, buf: &mut BytesMut) {
+    for car in data.car_lap_data.iter() {
+        buf.put_u32_le(car.last_lap_time);
+        buf.put_u32_le(car.current_lap_time);
+        buf.put_u16_le(car.sector1_time);
+        buf.put_u16_le(car.sector2_time);
+        buf.put_f32_le(car.lap_distance);
+        buf.put_f32_le(car.total_distance);
+        buf.put_f32_le(car.safety_car_delta);
+        buf.put_u8(car.car_position);
+        buf.put_u8(car.current_lap_num);
+        buf.put_u8(car.pit_status);
+        buf.put_u8(car.num_pit_stops);
+        buf.put_u8(car.sector);
+        buf.put_u8(car.current_lap_invalid as u8);
+        buf.put_u8(car.penalties);
+        buf.put_u8(car.warnings);
+        buf.put_u8(car.num_unserved_drive_through_pens);
+        buf.put_u8(car.num_unserved_stop_go_pens);
+        buf.put_u8(car.grid_position);
+        buf.put_u8(car.driver_status);
+        buf.put_u8(car.result_status);
+        buf.put_u8(car.pit_lane_timer_active as u8);
+        buf.put_u16_le(car.pit_lane_time_in_lane);
+        buf.put_u16_le(car.pit_stop_timer);
+        buf.put_u8(car.pit_stop_should_serve_pen as u8);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LapData {
+    pub car_lap_data: [CarLapData; NUMBER_OF_CARS],
+}
+
+/// Lap and sector times are in milliseconds
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarLapData {
+    pub last_lap_time: u32,
+    pub current_lap_time: u32,
+    pub sector1_time: u16,
+    pub sector2_time: u16,
+    pub lap_distance: f32,
+    pub total_distance: f32,
+    pub safety_car_delta: f32,
+    pub car_position: u8,
+    pub current_lap_num: u8,
+    pub pit_status: u8,
+    pub num_pit_stops: u8,
+    pub sector: u8,
+    pub current_lap_invalid: bool,
+    pub penalties: u8,
+    pub warnings: u8,
+    pub num_unserved_drive_through_pens: u8,
+    pub num_unserved_stop_go_pens: u8,
+    pub grid_position: u8,
+    pub driver_status: u8,
+    pub result_status: u8,
+    pub pit_lane_timer_active: bool,
+    pub pit_lane_time_in_lane: u16,
+    pub pit_stop_timer: u16,
+    pub pit_stop_should_serve_pen: bool,
+}
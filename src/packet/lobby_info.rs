@@ -0,0 +1,87 @@
+#![allow(clippy::needless_range_loop)]
+
+use alloc::string::String;
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::packet::appendix::{Nationality, Team};
+use crate::utils::*;
+
+pub const LOBBY_INFO_PACKET_SIZE: usize = 1191 - packet::header::HEADER_SIZE;
+
+pub fn parse_lobby_info_packet(buf: &mut impl Buf) -> Result<LobbyData, F1Error> {
+    if buf.remaining() < LOBBY_INFO_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let num_players = buf.get_u8();
+
+    let mut lobby_players: [LobbyInfoData; NUMBER_OF_CARS] = Default::default();
+
+    for i in 0..NUMBER_OF_CARS {
+        let ai_controlled = buf.get_u8() == 1;
+        let team_id = buf.get_u8();
+        let nationality = buf.get_u8();
+        let name = parse_string(buf, NAME_SIZE)?;
+        let car_number = buf.get_u8();
+        let ready_status = buf.get_u8();
+
+        lobby_players[i] = LobbyInfoData {
+            ai_controlled,
+            team_id,
+            nationality,
+            name,
+            car_number,
+            ready_status,
+        };
+    }
+
+    let packet = LobbyData {
+        num_players,
+        lobby_players,
+    };
+
+    Ok(packet)
+}
+
+pub fn write_lobby_info_packet(data: &LobbyData, buf: &mut BytesMut) {
+    buf.put_u8(data.num_players);
+
+    for player in data.lobby_players.iter() {
+        buf.put_u8(player.ai_controlled as u8);
+        buf.put_u8(player.team_id);
+        buf.put_u8(player.nationality);
+        write_string(buf, &player.name, NAME_SIZE);
+        buf.put_u8(player.car_number);
+        buf.put_u8(player.ready_status);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LobbyData {
+    pub num_players: u8,
+    pub lobby_players: [LobbyInfoData; NUMBER_OF_CARS],
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LobbyInfoData {
+    pub ai_controlled: bool,
+    pub team_id: u8,
+    pub nationality: u8,
+    pub name: String,
+    pub car_number: u8,
+    pub ready_status: u8,
+}
+
+impl LobbyInfoData {
+    pub fn team(&self) -> Team {
+        self.team_id.into()
+    }
+
+    pub fn nationality(&self) -> Nationality {
+        self.nationality.into()
+    }
+}
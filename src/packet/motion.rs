@@ -1,14 +1,17 @@
 #![allow(clippy::needless_range_loop)]
-use bytes::{Buf, BytesMut};
-use std::io::Cursor;
+use bytes::{Buf, BufMut, BytesMut};
 
 use crate::error::F1Error;
 use crate::packet;
+use crate::packet::header::{parse_header, Header, PacketId};
 use crate::utils::*;
 
 pub const MOTION_PACKET_SIZE: usize = 1464 - packet::header::HEADER_SIZE;
 
-pub fn parse_motion_packet(buf: &mut Cursor<&mut BytesMut>) -> Result<MotionData, F1Error> {
+const CAR_MOTION_DATA_SIZE: usize = 60;
+const CAR_MOTION_DATA_END: usize = NUMBER_OF_CARS * CAR_MOTION_DATA_SIZE;
+
+pub fn parse_motion_packet(buf: &mut impl Buf) -> Result<MotionData, F1Error> {
     if buf.remaining() < MOTION_PACKET_SIZE {
         return Err(F1Error::IncompleteData);
     }
@@ -81,8 +84,38 @@ pub fn parse_motion_packet(buf: &mut Cursor<&mut BytesMut>) -> Result<MotionData
     Ok(packet)
 }
 
+pub fn write_motion_packet(data: &MotionData, buf: &mut BytesMut) {
+    for car in data.car_motion_data.iter() {
+        write_coordinates_3d_f32(buf, &car.world_positon);
+        write_coordinates_3d_f32(buf, &car.world_velocity);
+        write_coordinates_3d_i16(buf, &car.world_forward_dir);
+        write_coordinates_3d_i16(buf, &car.world_right_dir);
+
+        buf.put_f32_le(car.g_force_lateral);
+        buf.put_f32_le(car.g_force_longitudinal);
+        buf.put_f32_le(car.g_force_vertical);
+
+        buf.put_f32_le(car.yaw);
+        buf.put_f32_le(car.pitch);
+        buf.put_f32_le(car.roll);
+    }
+
+    write_wheels_data_f32(buf, &data.suspension_position);
+    write_wheels_data_f32(buf, &data.suspension_velocity);
+    write_wheels_data_f32(buf, &data.suspension_acceleration);
+    write_wheels_data_f32(buf, &data.wheel_speed);
+    write_wheels_data_f32(buf, &data.wheel_slip);
+    write_coordinates_3d_f32(buf, &data.local_velocity);
+    write_coordinates_3d_f32(buf, &data.angular_velocity);
+    write_coordinates_3d_f32(buf, &data.angular_acceleration);
+    buf.put_f32_le(data.front_wheels_angle);
+}
+
 #[derive(Debug, Clone, PartialEq, Default, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CarMotionData {
+    /// Serialized as `world_position`, the field name keeps its original spelling
+    #[cfg_attr(feature = "serde", serde(rename = "world_position"))]
     pub world_positon: Coordinates3D<f32>,
     pub world_velocity: Coordinates3D<f32>,
     pub world_forward_dir: Coordinates3D<i16>,
@@ -96,6 +129,7 @@ pub struct CarMotionData {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MotionData {
     pub car_motion_data: [CarMotionData; NUMBER_OF_CARS],
 
@@ -109,3 +143,163 @@ pub struct MotionData {
     pub angular_acceleration: Coordinates3D<f32>,
     pub front_wheels_angle: f32,
 }
+
+/// Motion packet borrowed from the raw datagram, fields are decoded when they are read
+#[derive(Debug, Clone)]
+pub struct MotionView<'a> {
+    header: Header,
+    data: &'a [u8],
+}
+
+impl<'a> MotionView<'a> {
+    /// Wraps a whole datagram, only the header is decoded
+    /// Fails with `ConversionError` if it isn't a Motion packet
+    pub fn new(datagram: &'a [u8]) -> Result<Self, F1Error> {
+        let mut buf = datagram;
+        let header = parse_header(&mut buf)?;
+
+        if header.packet_id != PacketId::Motion {
+            return Err(F1Error::ConversionError);
+        }
+
+        if buf.len() < MOTION_PACKET_SIZE {
+            return Err(F1Error::IncompleteData);
+        }
+
+        Ok(MotionView {
+            header,
+            data: &buf[..MOTION_PACKET_SIZE],
+        })
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns `None` if the index is out of range
+    pub fn car(&self, index: usize) -> Option<CarMotionView<'a>> {
+        if index >= NUMBER_OF_CARS {
+            return None;
+        }
+
+        let start = index * CAR_MOTION_DATA_SIZE;
+
+        Some(CarMotionView {
+            data: &self.data[start..start + CAR_MOTION_DATA_SIZE],
+        })
+    }
+
+    pub fn player_car(&self) -> Option<CarMotionView<'a>> {
+        self.car(self.header.player_car_index as usize)
+    }
+
+    pub fn cars(&self) -> impl Iterator<Item = CarMotionView<'a>> {
+        self.data[..CAR_MOTION_DATA_END]
+            .chunks_exact(CAR_MOTION_DATA_SIZE)
+            .map(|data| CarMotionView { data })
+    }
+
+    pub fn suspension_position(&self) -> WheelsData<f32> {
+        parse_wheels_data_f32(&mut &self.data[CAR_MOTION_DATA_END..])
+    }
+
+    pub fn suspension_velocity(&self) -> WheelsData<f32> {
+        parse_wheels_data_f32(&mut &self.data[CAR_MOTION_DATA_END + 16..])
+    }
+
+    pub fn suspension_acceleration(&self) -> WheelsData<f32> {
+        parse_wheels_data_f32(&mut &self.data[CAR_MOTION_DATA_END + 32..])
+    }
+
+    pub fn wheel_speed(&self) -> WheelsData<f32> {
+        parse_wheels_data_f32(&mut &self.data[CAR_MOTION_DATA_END + 48..])
+    }
+
+    pub fn wheel_slip(&self) -> WheelsData<f32> {
+        parse_wheels_data_f32(&mut &self.data[CAR_MOTION_DATA_END + 64..])
+    }
+
+    pub fn local_velocity(&self) -> Coordinates3D<f32> {
+        parse_coordinates_3d_f32(&mut &self.data[CAR_MOTION_DATA_END + 80..])
+    }
+
+    pub fn angular_velocity(&self) -> Coordinates3D<f32> {
+        parse_coordinates_3d_f32(&mut &self.data[CAR_MOTION_DATA_END + 92..])
+    }
+
+    pub fn angular_acceleration(&self) -> Coordinates3D<f32> {
+        parse_coordinates_3d_f32(&mut &self.data[CAR_MOTION_DATA_END + 104..])
+    }
+
+    pub fn front_wheels_angle(&self) -> f32 {
+        (&self.data[CAR_MOTION_DATA_END + 116..]).get_f32_le()
+    }
+
+    /// Decodes the whole packet, same as `parse_motion_packet`
+    pub fn decode(&self) -> MotionData {
+        parse_motion_packet(&mut &self.data[..]).expect("size is checked in MotionView::new")
+    }
+}
+
+/// Motion data of a single car, borrowed from a `MotionView`
+#[derive(Debug, Clone, Copy)]
+pub struct CarMotionView<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CarMotionView<'a> {
+    pub fn world_position(&self) -> Coordinates3D<f32> {
+        parse_coordinates_3d_f32(&mut &self.data[0..])
+    }
+
+    pub fn world_velocity(&self) -> Coordinates3D<f32> {
+        parse_coordinates_3d_f32(&mut &self.data[12..])
+    }
+
+    pub fn world_forward_dir(&self) -> Coordinates3D<i16> {
+        parse_coordinates_3d_i16(&mut &self.data[24..])
+    }
+
+    pub fn world_right_dir(&self) -> Coordinates3D<i16> {
+        parse_coordinates_3d_i16(&mut &self.data[30..])
+    }
+
+    pub fn g_force_lateral(&self) -> f32 {
+        (&self.data[36..]).get_f32_le()
+    }
+
+    pub fn g_force_longitudinal(&self) -> f32 {
+        (&self.data[40..]).get_f32_le()
+    }
+
+    pub fn g_force_vertical(&self) -> f32 {
+        (&self.data[44..]).get_f32_le()
+    }
+
+    pub fn yaw(&self) -> f32 {
+        (&self.data[48..]).get_f32_le()
+    }
+
+    pub fn pitch(&self) -> f32 {
+        (&self.data[52..]).get_f32_le()
+    }
+
+    pub fn roll(&self) -> f32 {
+        (&self.data[56..]).get_f32_le()
+    }
+
+    pub fn decode(&self) -> CarMotionData {
+        CarMotionData {
+            world_positon: self.world_position(),
+            world_velocity: self.world_velocity(),
+            world_forward_dir: self.world_forward_dir(),
+            world_right_dir: self.world_right_dir(),
+            g_force_lateral: self.g_force_lateral(),
+            g_force_longitudinal: self.g_force_longitudinal(),
+            g_force_vertical: self.g_force_vertical(),
+            yaw: self.yaw(),
+            pitch: self.pitch(),
+            roll: self.roll(),
+        }
+    }
+}
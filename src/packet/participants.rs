@@ -0,0 +1,103 @@
+#![allow(clippy::needless_range_loop)]
+
+use alloc::string::String;
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::packet::appendix::{Driver, Nationality, Team};
+use crate::utils::*;
+
+pub const PARTICIPANTS_PACKET_SIZE: usize = 1257 - packet::header::HEADER_SIZE;
+
+pub fn parse_participants_packet(buf: &mut impl Buf) -> Result<ParticipantsData, F1Error> {
+    if buf.remaining() < PARTICIPANTS_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let num_active_cars = buf.get_u8();
+
+    let mut participants: [ParticipantData; NUMBER_OF_CARS] = Default::default();
+
+    for i in 0..NUMBER_OF_CARS {
+        let ai_controlled = buf.get_u8() == 1;
+        let driver_id = buf.get_u8();
+        let network_id = buf.get_u8();
+        let team_id = buf.get_u8();
+        let my_team = buf.get_u8() == 1;
+        let race_number = buf.get_u8();
+        let nationality = buf.get_u8();
+        let name = parse_string(buf, NAME_SIZE)?;
+        let public_telemetry = buf.get_u8() == 1;
+
+        participants[i] = ParticipantData {
+            ai_controlled,
+            driver_id,
+            network_id,
+            team_id,
+            my_team,
+            race_number,
+            nationality,
+            name,
+            public_telemetry,
+        };
+    }
+
+    let packet = ParticipantsData {
+        num_active_cars,
+        participants,
+    };
+
+    Ok(packet)
+}
+
+pub fn write_participants_packet(data: &ParticipantsData, buf: &mut BytesMut) {
+    buf.put_u8(data.num_active_cars);
+
+    for participant in data.participants.iter() {
+        buf.put_u8(participant.ai_controlled as u8);
+        buf.put_u8(participant.driver_id);
+        buf.put_u8(participant.network_id);
+        buf.put_u8(participant.team_id);
+        buf.put_u8(participant.my_team as u8);
+        buf.put_u8(participant.race_number);
+        buf.put_u8(participant.nationality);
+        write_string(buf, &participant.name, NAME_SIZE);
+        buf.put_u8(participant.public_telemetry as u8);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParticipantsData {
+    pub num_active_cars: u8,
+    pub participants: [ParticipantData; NUMBER_OF_CARS],
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParticipantData {
+    pub ai_controlled: bool,
+    pub driver_id: u8,
+    pub network_id: u8,
+    pub team_id: u8,
+    pub my_team: bool,
+    pub race_number: u8,
+    pub nationality: u8,
+    pub name: String,
+    pub public_telemetry: bool,
+}
+
+impl ParticipantData {
+    pub fn driver(&self) -> Driver {
+        self.driver_id.into()
+    }
+
+    pub fn team(&self) -> Team {
+        self.team_id.into()
+    }
+
+    pub fn nationality(&self) -> Nationality {
+        self.nationality.into()
+    }
+}
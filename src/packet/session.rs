@@ -0,0 +1,254 @@
+#![allow(clippy::needless_range_loop)]
+
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::packet::appendix::Track;
+
+pub const SESSION_PACKET_SIZE: usize = 625 - packet::header::HEADER_SIZE;
+
+pub const MAX_MARSHAL_ZONES: usize = 21;
+pub const MAX_WEATHER_FORECAST_SAMPLES: usize = 56;
+
+pub fn parse_session_packet(buf: &mut impl Buf) -> Result<SessionData, F1Error> {
+    if buf.remaining() < SESSION_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let weather = buf.get_u8();
+    let track_temperature = buf.get_i8();
+    let air_temperature = buf.get_i8();
+    let total_laps = buf.get_u8();
+    let track_length = buf.get_u16_le();
+    let session_type = buf.get_u8();
+    let track_id = buf.get_i8();
+    let formula = buf.get_u8();
+    let session_time_left = buf.get_u16_le();
+    let session_duration = buf.get_u16_le();
+    let pit_speed_limit = buf.get_u8();
+    let game_paused = buf.get_u8() == 1;
+    let is_spectating = buf.get_u8() == 1;
+    let spectator_car_index = buf.get_u8();
+    let sli_pro_native_support = buf.get_u8() == 1;
+    let num_marshal_zones = buf.get_u8();
+
+    let mut marshal_zones: [MarshalZone; MAX_MARSHAL_ZONES] =
+        [MarshalZone::default(); MAX_MARSHAL_ZONES];
+
+    for i in 0..MAX_MARSHAL_ZONES {
+        marshal_zones[i] = MarshalZone {
+            zone_start: buf.get_f32_le(),
+            zone_flag: buf.get_i8(),
+        };
+    }
+
+    let safety_car_status = buf.get_u8();
+    let network_game = buf.get_u8() == 1;
+    let num_weather_forecast_samples = buf.get_u8();
+
+    let mut weather_forecast_samples: [WeatherForecastSample; MAX_WEATHER_FORECAST_SAMPLES] =
+        [WeatherForecastSample::default(); MAX_WEATHER_FORECAST_SAMPLES];
+
+    for i in 0..MAX_WEATHER_FORECAST_SAMPLES {
+        weather_forecast_samples[i] = WeatherForecastSample {
+            session_type: buf.get_u8(),
+            time_offset: buf.get_u8(),
+            weather: buf.get_u8(),
+            track_temperature: buf.get_i8(),
+            track_temperature_change: buf.get_i8(),
+            air_temperature: buf.get_i8(),
+            air_temperature_change: buf.get_i8(),
+            rain_percentage: buf.get_u8(),
+        };
+    }
+
+    let forecast_accuracy = buf.get_u8();
+    let ai_difficulty = buf.get_u8();
+    let season_link_identifier = buf.get_u32_le();
+    let weekend_link_identifier = buf.get_u32_le();
+    let session_link_identifier = buf.get_u32_le();
+    let pit_stop_window_ideal_lap = buf.get_u8();
+    let pit_stop_window_latest_lap = buf.get_u8();
+    let pit_stop_rejoin_position = buf.get_u8();
+    let steering_assist = buf.get_u8() == 1;
+    let braking_assist = buf.get_u8();
+    let gearbox_assist = buf.get_u8();
+    let pit_assist = buf.get_u8() == 1;
+    let pit_release_assist = buf.get_u8() == 1;
+    let ers_assist = buf.get_u8() == 1;
+    let drs_assist = buf.get_u8() == 1;
+    let dynamic_racing_line = buf.get_u8();
+    let dynamic_racing_line_type = buf.get_u8();
+
+    let packet = SessionData {
+        weather,
+        track_temperature,
+        air_temperature,
+        total_laps,
+        track_length,
+        session_type,
+        track_id,
+        formula,
+        session_time_left,
+        session_duration,
+        pit_speed_limit,
+        game_paused,
+        is_spectating,
+        spectator_car_index,
+        sli_pro_native_support,
+        num_marshal_zones,
+        marshal_zones,
+        safety_car_status,
+        network_game,
+        num_weather_forecast_samples,
+        weather_forecast_samples,
+        forecast_accuracy,
+        ai_difficulty,
+        season_link_identifier,
+        weekend_link_identifier,
+        session_link_identifier,
+        pit_stop_window_ideal_lap,
+        pit_stop_window_latest_lap,
+        pit_stop_rejoin_position,
+        steering_assist,
+        braking_assist,
+        gearbox_assist,
+        pit_assist,
+        pit_release_assist,
+        ers_assist,
+        drs_assist,
+        dynamic_racing_line,
+        dynamic_racing_line_type,
+    };
+
+    Ok(packet)
+}
+
+pub fn write_session_packet(data: &SessionData, buf: &mut BytesMut) {
+    buf.put_u8(data.weather);
+    buf.put_i8(data.track_temperature);
+    buf.put_i8(data.air_temperature);
+    buf.put_u8(data.total_laps);
+    buf.put_u16_le(data.track_length);
+    buf.put_u8(data.session_type);
+    buf.put_i8(data.track_id);
+    buf.put_u8(data.formula);
+    buf.put_u16_le(data.session_time_left);
+    buf.put_u16_le(data.session_duration);
+    buf.put_u8(data.pit_speed_limit);
+    buf.put_u8(data.game_paused as u8);
+    buf.put_u8(data.is_spectating as u8);
+    buf.put_u8(data.spectator_car_index);
+    buf.put_u8(data.sli_pro_native_support as u8);
+    buf.put_u8(data.num_marshal_zones);
+
+    for zone in data.marshal_zones.iter() {
+        buf.put_f32_le(zone.zone_start);
+        buf.put_i8(zone.zone_flag);
+    }
+
+    buf.put_u8(data.safety_car_status);
+    buf.put_u8(data.network_game as u8);
+    buf.put_u8(data.num_weather_forecast_samples);
+
+    for sample in data.weather_forecast_samples.iter() {
+        buf.put_u8(sample.session_type);
+        buf.put_u8(sample.time_offset);
+        buf.put_u8(sample.weather);
+        buf.put_i8(sample.track_temperature);
+        buf.put_i8(sample.track_temperature_change);
+        buf.put_i8(sample.air_temperature);
+        buf.put_i8(sample.air_temperature_change);
+        buf.put_u8(sample.rain_percentage);
+    }
+
+    buf.put_u8(data.forecast_accuracy);
+    buf.put_u8(data.ai_difficulty);
+    buf.put_u32_le(data.season_link_identifier);
+    buf.put_u32_le(data.weekend_link_identifier);
+    buf.put_u32_le(data.session_link_identifier);
+    buf.put_u8(data.pit_stop_window_ideal_lap);
+    buf.put_u8(data.pit_stop_window_latest_lap);
+    buf.put_u8(data.pit_stop_rejoin_position);
+    buf.put_u8(data.steering_assist as u8);
+    buf.put_u8(data.braking_assist);
+    buf.put_u8(data.gearbox_assist);
+    buf.put_u8(data.pit_assist as u8);
+    buf.put_u8(data.pit_release_assist as u8);
+    buf.put_u8(data.ers_assist as u8);
+    buf.put_u8(data.drs_assist as u8);
+    buf.put_u8(data.dynamic_racing_line);
+    buf.put_u8(data.dynamic_racing_line_type);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionData {
+    pub weather: u8,
+    pub track_temperature: i8,
+    pub air_temperature: i8,
+    pub total_laps: u8,
+    pub track_length: u16,
+    pub session_type: u8,
+    pub track_id: i8,
+    pub formula: u8,
+    pub session_time_left: u16,
+    pub session_duration: u16,
+    pub pit_speed_limit: u8,
+    pub game_paused: bool,
+    pub is_spectating: bool,
+    pub spectator_car_index: u8,
+    pub sli_pro_native_support: bool,
+    pub num_marshal_zones: u8,
+    pub marshal_zones: [MarshalZone; MAX_MARSHAL_ZONES],
+    pub safety_car_status: u8,
+    pub network_game: bool,
+    pub num_weather_forecast_samples: u8,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub weather_forecast_samples: [WeatherForecastSample; MAX_WEATHER_FORECAST_SAMPLES],
+    pub forecast_accuracy: u8,
+    pub ai_difficulty: u8,
+    pub season_link_identifier: u32,
+    pub weekend_link_identifier: u32,
+    pub session_link_identifier: u32,
+    pub pit_stop_window_ideal_lap: u8,
+    pub pit_stop_window_latest_lap: u8,
+    pub pit_stop_rejoin_position: u8,
+    pub steering_assist: bool,
+    pub braking_assist: u8,
+    pub gearbox_assist: u8,
+    pub pit_assist: bool,
+    pub pit_release_assist: bool,
+    pub ers_assist: bool,
+    pub drs_assist: bool,
+    pub dynamic_racing_line: u8,
+    pub dynamic_racing_line_type: u8,
+}
+
+impl SessionData {
+    /// An unknown track, sent as -1, is returned as `Track::Unknown(255)`
+    pub fn track(&self) -> Track {
+        (self.track_id as u8).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarshalZone {
+    pub zone_start: f32,
+    pub zone_flag: i8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeatherForecastSample {
+    pub session_type: u8,
+    pub time_offset: u8,
+    pub weather: u8,
+    pub track_temperature: i8,
+    pub track_temperature_change: i8,
+    pub air_temperature: i8,
+    pub air_temperature_change: i8,
+    pub rain_percentage: u8,
+}
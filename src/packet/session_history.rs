@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+use bytes::{Buf, BufMut, BytesMut};
+
+use crate::error::F1Error;
+use crate::packet;
+use crate::utils::*;
+
+pub const SESSION_HISTORY_PACKET_SIZE: usize = 1155 - packet::header::HEADER_SIZE;
+
+pub const MAX_LAPS_IN_HISTORY: usize = 100;
+
+const LAP_HISTORY_DATA_SIZE: usize = 11;
+const TYRE_STINT_HISTORY_DATA_SIZE: usize = 3;
+
+pub fn parse_session_history_packet(buf: &mut impl Buf) -> Result<SessionHistoryData, F1Error> {
+    if buf.remaining() < SESSION_HISTORY_PACKET_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let car_idx = buf.get_u8();
+    let num_laps = buf.get_u8();
+    let num_tyre_stints = buf.get_u8();
+    let best_lap_time_lap_num = buf.get_u8();
+    let best_sector1_lap_num = buf.get_u8();
+    let best_sector2_lap_num = buf.get_u8();
+    let best_sector3_lap_num = buf.get_u8();
+
+    // All entries are always sent, only the first num_laps and num_tyre_stints are valid
+    let mut lap_history_data = Vec::with_capacity(num_laps as usize);
+
+    for i in 0..MAX_LAPS_IN_HISTORY {
+        let lap_time = buf.get_u32_le();
+        let sector1_time = buf.get_u16_le();
+        let sector2_time = buf.get_u16_le();
+        let sector3_time = buf.get_u16_le();
+        let lap_valid_bit_flags = buf.get_u8();
+
+        if i < num_laps as usize {
+            lap_history_data.push(LapHistoryData {
+                lap_time,
+                sector1_time,
+                sector2_time,
+                sector3_time,
+                lap_valid: lap_valid_bit_flags & 0x01 != 0,
+                sector1_valid: lap_valid_bit_flags & 0x02 != 0,
+                sector2_valid: lap_valid_bit_flags & 0x04 != 0,
+                sector3_valid: lap_valid_bit_flags & 0x08 != 0,
+            });
+        }
+    }
+
+    let mut tyre_stints_history_data = Vec::with_capacity(num_tyre_stints as usize);
+
+    for i in 0..MAX_TYRE_STINTS {
+        let end_lap = buf.get_u8();
+        let tyre_actual_compound = buf.get_u8();
+        let tyre_visual_compound = buf.get_u8();
+
+        if i < num_tyre_stints as usize {
+            tyre_stints_history_data.push(TyreStintHistoryData {
+                end_lap,
+                tyre_actual_compound,
+                tyre_visual_compound,
+            });
+        }
+    }
+
+    let packet = SessionHistoryData {
+        car_idx,
+        num_laps,
+        num_tyre_stints,
+        best_lap_time_lap_num,
+        best_sector1_lap_num,
+        best_sector2_lap_num,
+        best_sector3_lap_num,
+        lap_history_data,
+        tyre_stints_history_data,
+    };
+
+    Ok(packet)
+}
+
+/// Missing laps and tyre stints are padded with zeroes
+pub fn write_session_history_packet(data: &SessionHistoryData, buf: &mut BytesMut) {
+    buf.put_u8(data.car_idx);
+    buf.put_u8(data.num_laps);
+    buf.put_u8(data.num_tyre_stints);
+    buf.put_u8(data.best_lap_time_lap_num);
+    buf.put_u8(data.best_sector1_lap_num);
+    buf.put_u8(data.best_sector2_lap_num);
+    buf.put_u8(data.best_sector3_lap_num);
+
+    for lap in data.lap_history_data.iter().take(MAX_LAPS_IN_HISTORY) {
+        let lap_valid_bit_flags = lap.lap_valid as u8
+            | (lap.sector1_valid as u8) << 1
+            | (lap.sector2_valid as u8) << 2
+            | (lap.sector3_valid as u8) << 3;
+
+        buf.put_u32_le(lap.lap_time);
+        buf.put_u16_le(lap.sector1_time);
+        buf.put_u16_le(lap.sector2_time);
+        buf.put_u16_le(lap.sector3_time);
+        buf.put_u8(lap_valid_bit_flags);
+    }
+
+    let missing_laps = MAX_LAPS_IN_HISTORY.saturating_sub(data.lap_history_data.len());
+    buf.put_bytes(0, missing_laps * LAP_HISTORY_DATA_SIZE);
+
+    for stint in data.tyre_stints_history_data.iter().take(MAX_TYRE_STINTS) {
+        buf.put_u8(stint.end_lap);
+        buf.put_u8(stint.tyre_actual_compound);
+        buf.put_u8(stint.tyre_visual_compound);
+    }
+
+    let missing_stints = MAX_TYRE_STINTS.saturating_sub(data.tyre_stints_history_data.len());
+    buf.put_bytes(0, missing_stints * TYRE_STINT_HISTORY_DATA_SIZE);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionHistoryData {
+    pub car_idx: u8,
+    pub num_laps: u8,
+    pub num_tyre_stints: u8,
+    pub best_lap_time_lap_num: u8,
+    pub best_sector1_lap_num: u8,
+    pub best_sector2_lap_num: u8,
+    pub best_sector3_lap_num: u8,
+    pub lap_history_data: Vec<LapHistoryData>,
+    pub tyre_stints_history_data: Vec<TyreStintHistoryData>,
+}
+
+/// Lap and sector times are in milliseconds
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LapHistoryData {
+    pub lap_time: u32,
+    pub sector1_time: u16,
+    pub sector2_time: u16,
+    pub sector3_time: u16,
+    pub lap_valid: bool,
+    pub sector1_valid: bool,
+    pub sector2_valid: bool,
+    pub sector3_valid: bool,
+}
+
+/// End lap is 255 for the current stint
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TyreStintHistoryData {
+    pub end_lap: u8,
+    pub tyre_actual_compound: u8,
+    pub tyre_visual_compound: u8,
+}
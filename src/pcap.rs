@@ -0,0 +1,743 @@
+//! Reads game traffic from pcap and pcapng captures and writes captures Wireshark can open
+//!
+//! Only unfragmented UDP over IPv4 or IPv6 is extracted, on Ethernet, raw IP,
+//! Linux cooked (SLL and SLL2) and BSD loopback link types.
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::BufMut;
+
+use crate::error::F1Error;
+use crate::packet::Packet;
+use crate::utils::read_full;
+use crate::ReceivedPacket;
+
+/// Ports the game sends to by default
+pub const DEFAULT_PORTS: [u16; 2] = [20777, 2021];
+
+const PCAP_MAGIC_MICROS: u32 = 0xa1b2c3d4;
+const PCAP_MAGIC_NANOS: u32 = 0xa1b23c4d;
+const PCAPNG_SECTION_HEADER: u32 = 0x0a0d0d0a;
+const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1a2b3c4d;
+
+const PCAPNG_INTERFACE_DESCRIPTION: u32 = 1;
+const PCAPNG_OBSOLETE_PACKET: u32 = 2;
+const PCAPNG_SIMPLE_PACKET: u32 = 3;
+const PCAPNG_ENHANCED_PACKET: u32 = 6;
+const PCAPNG_OPTION_TSRESOL: u16 = 9;
+
+const LINKTYPE_NULL: u32 = 0;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_LOOP: u32 = 108;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+const LINKTYPE_IPV4: u32 = 228;
+const LINKTYPE_IPV6: u32 = 229;
+const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const ETHERTYPE_VLAN: [u16; 3] = [0x8100, 0x88a8, 0x9100];
+
+const IP_PROTOCOL_UDP: u8 = 17;
+const UDP_HEADER_SIZE: usize = 8;
+
+/// Larger frames or blocks are treated as a corrupt capture
+const MAX_BLOCK_SIZE: usize = 256 * 1024;
+
+/// UDP payload extracted from a capture
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedDatagram {
+    /// Simple Packet Blocks in pcapng have no timestamp, they use the Unix epoch
+    pub timestamp: SystemTime,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub payload: Vec<u8>,
+}
+
+impl CapturedDatagram {
+    pub fn packet(&self) -> Result<Packet, F1Error> {
+        Packet::parse(&self.payload)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Interface {
+    link_type: u32,
+    /// Timestamp units per second
+    resolution: u64,
+}
+
+enum Format {
+    Pcap {
+        big_endian: bool,
+        interface: Interface,
+    },
+    PcapNg {
+        big_endian: bool,
+        interfaces: Vec<Interface>,
+    },
+}
+
+/// Reads the game's UDP datagrams from a pcap or pcapng capture
+/// Iterating returns the decoded packets, use `next_datagram` to get the payloads instead
+pub struct PcapReader<R: Read> {
+    reader: R,
+    format: Format,
+    ports: Option<Vec<u16>>,
+}
+
+impl PcapReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, F1Error> {
+        PcapReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Detects the capture format, only datagrams sent to `DEFAULT_PORTS` are returned
+    pub fn new(mut reader: R) -> Result<Self, F1Error> {
+        let mut magic = [0u8; 4];
+
+        if read_full(&mut reader, &mut magic)? < magic.len() {
+            return Err(F1Error::InvalidCapture);
+        }
+
+        let format = if u32::from_le_bytes(magic) == PCAPNG_SECTION_HEADER {
+            let big_endian = read_section_header(&mut reader)?;
+
+            Format::PcapNg {
+                big_endian,
+                interfaces: Vec::new(),
+            }
+        } else {
+            let (big_endian, resolution) =
+                match (u32::from_le_bytes(magic), u32::from_be_bytes(magic)) {
+                    (PCAP_MAGIC_MICROS, _) => (false, 1_000_000),
+                    (PCAP_MAGIC_NANOS, _) => (false, 1_000_000_000),
+                    (_, PCAP_MAGIC_MICROS) => (true, 1_000_000),
+                    (_, PCAP_MAGIC_NANOS) => (true, 1_000_000_000),
+                    _ => return Err(F1Error::InvalidCapture),
+                };
+
+            let mut header = [0u8; 20];
+
+            if read_full(&mut reader, &mut header)? < header.len() {
+                return Err(F1Error::InvalidCapture);
+            }
+
+            let link_type = read_u32(&header, 16, big_endian) & 0x0fff_ffff;
+
+            Format::Pcap {
+                big_endian,
+                interface: Interface {
+                    link_type,
+                    resolution,
+                },
+            }
+        };
+
+        Ok(PcapReader {
+            reader,
+            format,
+            ports: Some(DEFAULT_PORTS.to_vec()),
+        })
+    }
+
+    /// Only returns datagrams sent to one of these ports
+    pub fn with_ports(mut self, ports: &[u16]) -> Self {
+        self.ports = Some(ports.to_vec());
+        self
+    }
+
+    /// Returns UDP datagrams sent to any port
+    pub fn with_any_port(mut self) -> Self {
+        self.ports = None;
+        self
+    }
+
+    /// Returns `None` at the end of the capture, frames that aren't matching UDP datagrams are skipped
+    pub fn next_datagram(&mut self) -> Result<Option<CapturedDatagram>, F1Error> {
+        loop {
+            let (interface, timestamp, frame) = match self.next_frame()? {
+                Some(frame) => frame,
+                None => return Ok(None),
+            };
+
+            let (source, destination, payload) = match parse_frame(interface.link_type, &frame) {
+                Some(datagram) => datagram,
+                None => continue,
+            };
+
+            if let Some(ports) = &self.ports {
+                if !ports.contains(&destination.port()) {
+                    continue;
+                }
+            }
+
+            return Ok(Some(CapturedDatagram {
+                timestamp: to_system_time(timestamp, interface.resolution)?,
+                source,
+                destination,
+                payload: payload.to_vec(),
+            }));
+        }
+    }
+
+    /// Iterates over the raw datagrams
+    pub fn datagrams(self) -> Datagrams<R> {
+        Datagrams { reader: self }
+    }
+
+    fn next_frame(&mut self) -> Result<Option<(Interface, u64, Vec<u8>)>, F1Error> {
+        match &mut self.format {
+            Format::Pcap {
+                big_endian,
+                interface,
+            } => {
+                let mut header = [0u8; 16];
+
+                match read_full(&mut self.reader, &mut header)? {
+                    0 => return Ok(None),
+                    16 => {}
+                    _ => return Err(F1Error::IncompleteData),
+                }
+
+                let seconds = read_u32(&header, 0, *big_endian) as u64;
+                let fraction = read_u32(&header, 4, *big_endian) as u64;
+                let captured_length = read_u32(&header, 8, *big_endian) as usize;
+
+                let frame = read_block(&mut self.reader, captured_length)?;
+
+                Ok(Some((
+                    *interface,
+                    seconds * interface.resolution + fraction,
+                    frame,
+                )))
+            }
+            Format::PcapNg {
+                big_endian,
+                interfaces,
+            } => loop {
+                let mut header = [0u8; 8];
+
+                match read_full(&mut self.reader, &mut header)? {
+                    0 => return Ok(None),
+                    8 => {}
+                    _ => return Err(F1Error::IncompleteData),
+                }
+
+                if u32::from_le_bytes([header[0], header[1], header[2], header[3]])
+                    == PCAPNG_SECTION_HEADER
+                {
+                    // A new section can change the byte order, its interfaces start from 0
+                    let mut rest = [0u8; 4];
+                    rest.copy_from_slice(&header[4..]);
+                    *big_endian = read_section_header(&mut (&rest[..]).chain(&mut self.reader))?;
+                    interfaces.clear();
+                    continue;
+                }
+
+                let block_type = read_u32(&header, 0, *big_endian);
+                let block_length = read_u32(&header, 4, *big_endian) as usize;
+
+                if block_length < 12 || !block_length.is_multiple_of(4) {
+                    return Err(F1Error::InvalidCapture);
+                }
+
+                // Body and the trailing copy of the length
+                let block = read_block(&mut self.reader, block_length - 8)?;
+                let body = &block[..block.len() - 4];
+
+                match block_type {
+                    PCAPNG_INTERFACE_DESCRIPTION => {
+                        interfaces.push(parse_interface(body, *big_endian)?);
+                    }
+                    PCAPNG_ENHANCED_PACKET | PCAPNG_OBSOLETE_PACKET => {
+                        if body.len() < 20 {
+                            return Err(F1Error::InvalidCapture);
+                        }
+
+                        let interface_id = if block_type == PCAPNG_ENHANCED_PACKET {
+                            read_u32(body, 0, *big_endian) as usize
+                        } else {
+                            read_u16(body, 0, *big_endian) as usize
+                        };
+                        let timestamp = (read_u32(body, 4, *big_endian) as u64) << 32
+                            | read_u32(body, 8, *big_endian) as u64;
+                        let captured_length = read_u32(body, 12, *big_endian) as usize;
+
+                        let interface = interfaces
+                            .get(interface_id)
+                            .copied()
+                            .ok_or(F1Error::InvalidCapture)?;
+
+                        let frame = body
+                            .get(20..20 + captured_length)
+                            .ok_or(F1Error::InvalidCapture)?;
+
+                        return Ok(Some((interface, timestamp, frame.to_vec())));
+                    }
+                    PCAPNG_SIMPLE_PACKET => {
+                        if body.len() < 4 {
+                            return Err(F1Error::InvalidCapture);
+                        }
+
+                        let interface =
+                            interfaces.first().copied().ok_or(F1Error::InvalidCapture)?;
+                        let original_length = read_u32(body, 0, *big_endian) as usize;
+                        let frame = &body[4..body.len().min(4 + original_length)];
+
+                        return Ok(Some((interface, 0, frame.to_vec())));
+                    }
+                    _ => {}
+                }
+            },
+        }
+    }
+}
+
+impl<R: Read> Iterator for PcapReader<R> {
+    type Item = Result<(SystemTime, Packet), F1Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_datagram() {
+            Ok(Some(datagram)) => {
+                Some(datagram.packet().map(|packet| (datagram.timestamp, packet)))
+            }
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Iterator over the raw datagrams of a capture
+pub struct Datagrams<R: Read> {
+    reader: PcapReader<R>,
+}
+
+impl<R: Read> Iterator for Datagrams<R> {
+    type Item = Result<CapturedDatagram, F1Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next_datagram().transpose()
+    }
+}
+
+/// Writes datagrams to a pcap capture, with synthesized IP and UDP headers
+pub struct PcapWriter<W: Write> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl PcapWriter<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, F1Error> {
+        PcapWriter::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> PcapWriter<W> {
+    /// Writes the pcap file header, frames are raw IP packets with microsecond timestamps
+    pub fn new(mut writer: W) -> Result<Self, F1Error> {
+        let mut header = Vec::with_capacity(24);
+
+        header.put_u32_le(PCAP_MAGIC_MICROS);
+        header.put_u16_le(2);
+        header.put_u16_le(4);
+        header.put_i32_le(0);
+        header.put_u32_le(0);
+        header.put_u32_le(u16::MAX as u32);
+        header.put_u32_le(LINKTYPE_RAW);
+
+        writer.write_all(&header)?;
+
+        Ok(PcapWriter {
+            writer,
+            buf: Vec::new(),
+        })
+    }
+
+    /// IPv4 addresses are mapped to IPv6 when the other address is IPv6
+    /// Fails with `ConversionError` if the payload doesn't fit in a UDP datagram
+    pub fn write_datagram(
+        &mut self,
+        timestamp: SystemTime,
+        source: SocketAddr,
+        destination: SocketAddr,
+        payload: &[u8],
+    ) -> Result<(), F1Error> {
+        let udp_length = UDP_HEADER_SIZE + payload.len();
+
+        let (ip_header, pseudo_header) = match (source.ip(), destination.ip()) {
+            (IpAddr::V4(source_ip), IpAddr::V4(destination_ip)) => {
+                if udp_length + 20 > u16::MAX as usize {
+                    return Err(F1Error::ConversionError);
+                }
+
+                ipv4_headers(source_ip, destination_ip, udp_length)
+            }
+            (source_ip, destination_ip) => {
+                if udp_length > u16::MAX as usize {
+                    return Err(F1Error::ConversionError);
+                }
+
+                ipv6_headers(to_ipv6(source_ip), to_ipv6(destination_ip), udp_length)
+            }
+        };
+
+        let mut udp_header = Vec::with_capacity(UDP_HEADER_SIZE);
+        udp_header.put_u16(source.port());
+        udp_header.put_u16(destination.port());
+        udp_header.put_u16(udp_length as u16);
+        udp_header.put_u16(0);
+
+        let checksum = match internet_checksum(&[&pseudo_header, &udp_header, payload]) {
+            0 => 0xffff,
+            checksum => checksum,
+        };
+        udp_header[6..8].copy_from_slice(&checksum.to_be_bytes());
+
+        let since_epoch = timestamp.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let frame_length = ip_header.len() + udp_length;
+
+        self.buf.clear();
+        self.buf.put_u32_le(since_epoch.as_secs() as u32);
+        self.buf.put_u32_le(since_epoch.subsec_micros());
+        self.buf.put_u32_le(frame_length as u32);
+        self.buf.put_u32_le(frame_length as u32);
+        self.buf.put_slice(&ip_header);
+        self.buf.put_slice(&udp_header);
+        self.buf.put_slice(payload);
+
+        self.writer.write_all(&self.buf)?;
+
+        Ok(())
+    }
+
+    /// Writes the datagram of a packet received by `F1_2021::telemetry_with_source`, unchanged
+    /// `destination` is the address the telemetry was bound to
+    pub fn write_received(
+        &mut self,
+        received: &ReceivedPacket,
+        destination: SocketAddr,
+    ) -> Result<(), F1Error> {
+        let age = Instant::now().saturating_duration_since(received.received_at);
+        let timestamp = SystemTime::now() - age;
+
+        self.write_datagram(timestamp, received.source, destination, &received.datagram)
+    }
+
+    pub fn flush(&mut self) -> Result<(), F1Error> {
+        Ok(self.writer.flush()?)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads the rest of a pcapng Section Header Block after its type, returns whether it's big endian
+fn read_section_header(reader: &mut impl Read) -> Result<bool, F1Error> {
+    let mut header = [0u8; 8];
+
+    if read_full(reader, &mut header)? < header.len() {
+        return Err(F1Error::InvalidCapture);
+    }
+
+    let big_endian = match u32::from_le_bytes([header[4], header[5], header[6], header[7]]) {
+        PCAPNG_BYTE_ORDER_MAGIC => false,
+        magic if magic.swap_bytes() == PCAPNG_BYTE_ORDER_MAGIC => true,
+        _ => return Err(F1Error::InvalidCapture),
+    };
+
+    let block_length = read_u32(&header, 0, big_endian) as usize;
+
+    if block_length < 28 || !block_length.is_multiple_of(4) {
+        return Err(F1Error::InvalidCapture);
+    }
+
+    // Version, section length, options and the trailing length are not needed
+    read_block(reader, block_length - 12)?;
+
+    Ok(big_endian)
+}
+
+fn parse_interface(body: &[u8], big_endian: bool) -> Result<Interface, F1Error> {
+    if body.len() < 8 {
+        return Err(F1Error::InvalidCapture);
+    }
+
+    let link_type = read_u16(body, 0, big_endian) as u32;
+    let mut resolution = 1_000_000;
+
+    let mut options = &body[8..];
+
+    while options.len() >= 4 {
+        let code = read_u16(options, 0, big_endian);
+        let length = read_u16(options, 2, big_endian) as usize;
+        let value = options.get(4..4 + length).ok_or(F1Error::InvalidCapture)?;
+
+        if code == 0 {
+            break;
+        }
+
+        if code == PCAPNG_OPTION_TSRESOL && length == 1 {
+            let exponent = (value[0] & 0x7f) as u32;
+
+            resolution = if value[0] & 0x80 == 0 {
+                10u64.checked_pow(exponent)
+            } else {
+                2u64.checked_pow(exponent)
+            }
+            .ok_or(F1Error::InvalidCapture)?;
+        }
+
+        let padded_length = (length + 3) & !3;
+        options = options.get(4 + padded_length..).unwrap_or_default();
+    }
+
+    Ok(Interface {
+        link_type,
+        resolution,
+    })
+}
+
+/// Returns the addresses and UDP payload, `None` if the frame isn't an unfragmented UDP datagram
+fn parse_frame(link_type: u32, frame: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    let ip_packet = match link_type {
+        LINKTYPE_ETHERNET => {
+            let mut ethertype = read_be_u16(frame, 12)?;
+            let mut offset = 14;
+
+            while ETHERTYPE_VLAN.contains(&ethertype) {
+                ethertype = read_be_u16(frame, offset + 2)?;
+                offset += 4;
+            }
+
+            match ethertype {
+                ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => frame.get(offset..)?,
+                _ => return None,
+            }
+        }
+        LINKTYPE_LINUX_SLL => match read_be_u16(frame, 14)? {
+            ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => frame.get(16..)?,
+            _ => return None,
+        },
+        LINKTYPE_LINUX_SLL2 => match read_be_u16(frame, 0)? {
+            ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => frame.get(20..)?,
+            _ => return None,
+        },
+        // The address family is in the capturing host's byte order, the IP version is enough
+        LINKTYPE_NULL | LINKTYPE_LOOP => frame.get(4..)?,
+        LINKTYPE_RAW | LINKTYPE_IPV4 | LINKTYPE_IPV6 => frame,
+        _ => return None,
+    };
+
+    let (source_ip, destination_ip, udp) = match ip_packet.first()? >> 4 {
+        4 => parse_ipv4(ip_packet)?,
+        6 => parse_ipv6(ip_packet)?,
+        _ => return None,
+    };
+
+    let source_port = read_be_u16(udp, 0)?;
+    let destination_port = read_be_u16(udp, 2)?;
+    let udp_length = read_be_u16(udp, 4)? as usize;
+
+    // Truncated by the capture's snap length
+    let payload = udp.get(UDP_HEADER_SIZE..udp_length)?;
+
+    Some((
+        SocketAddr::new(source_ip, source_port),
+        SocketAddr::new(destination_ip, destination_port),
+        payload,
+    ))
+}
+
+fn parse_ipv4(packet: &[u8]) -> Option<(IpAddr, IpAddr, &[u8])> {
+    let header_length = (*packet.first()? & 0x0f) as usize * 4;
+    let total_length = read_be_u16(packet, 2)? as usize;
+    let fragment = read_be_u16(packet, 6)?;
+
+    // More fragments flag or a fragment offset
+    if header_length < 20 || fragment & 0x3fff != 0 || *packet.get(9)? != IP_PROTOCOL_UDP {
+        return None;
+    }
+
+    let source: [u8; 4] = packet.get(12..16)?.try_into().ok()?;
+    let destination: [u8; 4] = packet.get(16..20)?.try_into().ok()?;
+
+    let end = total_length.min(packet.len());
+
+    Some((
+        IpAddr::V4(source.into()),
+        IpAddr::V4(destination.into()),
+        packet.get(header_length..end)?,
+    ))
+}
+
+fn parse_ipv6(packet: &[u8]) -> Option<(IpAddr, IpAddr, &[u8])> {
+    let payload_length = read_be_u16(packet, 4)? as usize;
+    let mut next_header = *packet.get(6)?;
+
+    let source: [u8; 16] = packet.get(8..24)?.try_into().ok()?;
+    let destination: [u8; 16] = packet.get(24..40)?.try_into().ok()?;
+
+    let mut offset = 40;
+
+    // Hop-by-hop, routing and destination options headers, fragments are not supported
+    while matches!(next_header, 0 | 43 | 60) {
+        next_header = *packet.get(offset)?;
+        offset += (*packet.get(offset + 1)? as usize + 1) * 8;
+    }
+
+    if next_header != IP_PROTOCOL_UDP {
+        return None;
+    }
+
+    let end = (40 + payload_length).min(packet.len());
+
+    Some((
+        IpAddr::V6(source.into()),
+        IpAddr::V6(destination.into()),
+        packet.get(offset..end)?,
+    ))
+}
+
+/// Returns the IPv4 header and the UDP checksum pseudo header
+fn ipv4_headers(source: Ipv4Addr, destination: Ipv4Addr, udp_length: usize) -> (Vec<u8>, Vec<u8>) {
+    let mut header = Vec::with_capacity(20);
+
+    header.put_u8(0x45);
+    header.put_u8(0);
+    header.put_u16((20 + udp_length) as u16);
+    header.put_u16(0);
+    // Don't fragment
+    header.put_u16(0x4000);
+    header.put_u8(64);
+    header.put_u8(IP_PROTOCOL_UDP);
+    header.put_u16(0);
+    header.put_slice(&source.octets());
+    header.put_slice(&destination.octets());
+
+    let checksum = internet_checksum(&[&header]);
+    header[10..12].copy_from_slice(&checksum.to_be_bytes());
+
+    let mut pseudo_header = Vec::with_capacity(12);
+    pseudo_header.put_slice(&source.octets());
+    pseudo_header.put_slice(&destination.octets());
+    pseudo_header.put_u8(0);
+    pseudo_header.put_u8(IP_PROTOCOL_UDP);
+    pseudo_header.put_u16(udp_length as u16);
+
+    (header, pseudo_header)
+}
+
+/// Returns the IPv6 header and the UDP checksum pseudo header
+fn ipv6_headers(source: Ipv6Addr, destination: Ipv6Addr, udp_length: usize) -> (Vec<u8>, Vec<u8>) {
+    let mut header = Vec::with_capacity(40);
+
+    header.put_u32(0x6000_0000);
+    header.put_u16(udp_length as u16);
+    header.put_u8(IP_PROTOCOL_UDP);
+    header.put_u8(64);
+    header.put_slice(&source.octets());
+    header.put_slice(&destination.octets());
+
+    let mut pseudo_header = Vec::with_capacity(40);
+    pseudo_header.put_slice(&source.octets());
+    pseudo_header.put_slice(&destination.octets());
+    pseudo_header.put_u32(udp_length as u32);
+    pseudo_header.put_u32(IP_PROTOCOL_UDP as u32);
+
+    (header, pseudo_header)
+}
+
+fn to_ipv6(ip: IpAddr) -> Ipv6Addr {
+    match ip {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+        IpAddr::V6(ip) => ip,
+    }
+}
+
+/// Ones' complement sum of big endian 16 bit words, as used by IP and UDP
+fn internet_checksum(parts: &[&[u8]]) -> u16 {
+    let mut sum = 0u32;
+    let mut odd_byte: Option<u8> = None;
+
+    for byte in parts.iter().flat_map(|part| part.iter()) {
+        match odd_byte.take() {
+            Some(high) => sum += u16::from_be_bytes([high, *byte]) as u32,
+            None => odd_byte = Some(*byte),
+        }
+    }
+
+    if let Some(high) = odd_byte {
+        sum += u16::from_be_bytes([high, 0]) as u32;
+    }
+
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+
+    !(sum as u16)
+}
+
+/// Fails with `InvalidCapture` for a time `SystemTime` can't represent
+fn to_system_time(timestamp: u64, resolution: u64) -> Result<SystemTime, F1Error> {
+    let seconds = timestamp / resolution;
+    let nanos = (timestamp % resolution) as u128 * 1_000_000_000 / resolution as u128;
+
+    UNIX_EPOCH
+        .checked_add(Duration::new(seconds, nanos as u32))
+        .ok_or(F1Error::InvalidCapture)
+}
+
+fn read_u16(buf: &[u8], offset: usize, big_endian: bool) -> u16 {
+    let bytes = [buf[offset], buf[offset + 1]];
+
+    if big_endian {
+        u16::from_be_bytes(bytes)
+    } else {
+        u16::from_le_bytes(bytes)
+    }
+}
+
+fn read_u32(buf: &[u8], offset: usize, big_endian: bool) -> u32 {
+    let bytes = [
+        buf[offset],
+        buf[offset + 1],
+        buf[offset + 2],
+        buf[offset + 3],
+    ];
+
+    if big_endian {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    }
+}
+
+fn read_be_u16(buf: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        buf.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_block(reader: &mut impl Read, length: usize) -> Result<Vec<u8>, F1Error> {
+    if length > MAX_BLOCK_SIZE {
+        return Err(F1Error::InvalidCapture);
+    }
+
+    let mut block = vec![0u8; length];
+
+    if read_full(reader, &mut block)? < length {
+        return Err(F1Error::IncompleteData);
+    }
+
+    Ok(block)
+}
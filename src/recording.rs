@@ -0,0 +1,332 @@
+//! Append-only file format for raw datagrams
+//!
+//! A recording starts with the 8 byte magic `F12021RC` and a `u16` format version.
+//! Every record follows as a `u32` length of the rest of the record, the receive time
+//! as `u64` microseconds since the Unix epoch, the source address (`u8` family 4 or 6,
+//! the IP bytes and a `u16` port) and the datagram as received.
+//! All integers are little endian, like the packets themselves.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::{Buf, BufMut};
+
+use crate::error::F1Error;
+use crate::packet::Packet;
+use crate::utils::read_full;
+
+pub const MAGIC: [u8; 8] = *b"F12021RC";
+pub const VERSION: u16 = 1;
+
+const FILE_HEADER_SIZE: usize = 10;
+/// Timestamp, address family and port
+const RECORD_FIXED_SIZE: usize = 8 + 1 + 2;
+/// An IPv6 source and the largest UDP payload, longer records are treated as corrupt
+const MAX_RECORD_SIZE: usize = RECORD_FIXED_SIZE + 16 + u16::MAX as usize;
+
+/// A raw datagram as it was received
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: SystemTime,
+    pub source: SocketAddr,
+    pub datagram: Vec<u8>,
+}
+
+impl Record {
+    pub fn packet(&self) -> Result<Packet, F1Error> {
+        Packet::parse(&self.datagram)
+    }
+}
+
+/// Writes datagrams to a recording
+pub struct Recorder<W: Write> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Creates a new recording, replacing the file if it exists
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, F1Error> {
+        Recorder::new(BufWriter::new(File::create(path)?))
+    }
+
+    /// Opens a recording to add records at its end, the file is created if it doesn't exist
+    /// A record cut short at the end of the file, e.g. by a crash while recording, is removed
+    pub fn append(path: impl AsRef<Path>) -> Result<Self, F1Error> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        if file.metadata()?.len() == 0 {
+            return Recorder::new(BufWriter::new(file));
+        }
+
+        let end = complete_records_end(&mut BufReader::new(&mut file))?;
+
+        file.set_len(end)?;
+        file.seek(SeekFrom::Start(end))?;
+
+        Ok(Recorder {
+            writer: BufWriter::new(file),
+            buf: Vec::new(),
+        })
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    /// Starts a new recording, the file header is written immediately
+    pub fn new(mut writer: W) -> Result<Self, F1Error> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+
+        Ok(Recorder {
+            writer,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Fails with `ConversionError` for a timestamp before the Unix epoch or too far after it
+    /// to be stored, or a datagram longer than a UDP payload
+    pub fn record(
+        &mut self,
+        timestamp: SystemTime,
+        source: SocketAddr,
+        datagram: &[u8],
+    ) -> Result<(), F1Error> {
+        let address_size = match source.ip() {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 16,
+        };
+        let record_size = RECORD_FIXED_SIZE + address_size + datagram.len();
+
+        if record_size > MAX_RECORD_SIZE {
+            return Err(F1Error::ConversionError);
+        }
+
+        let micros = timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| F1Error::ConversionError)?
+            .as_micros();
+        let micros = u64::try_from(micros).map_err(|_| F1Error::ConversionError)?;
+
+        self.buf.clear();
+        self.buf.put_u32_le(record_size as u32);
+        self.buf.put_u64_le(micros);
+
+        match source.ip() {
+            IpAddr::V4(ip) => {
+                self.buf.put_u8(4);
+                self.buf.put_slice(&ip.octets());
+            }
+            IpAddr::V6(ip) => {
+                self.buf.put_u8(6);
+                self.buf.put_slice(&ip.octets());
+            }
+        }
+
+        self.buf.put_u16_le(source.port());
+        self.buf.put_slice(datagram);
+
+        self.writer.write_all(&self.buf)?;
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), F1Error> {
+        Ok(self.writer.flush()?)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads a recording, iterating over the decoded packets and their receive time
+/// Use `next_record` to get the raw datagrams instead
+pub struct RecordingReader<R: Read> {
+    reader: R,
+    version: u16,
+    offset: u64,
+}
+
+impl RecordingReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, F1Error> {
+        RecordingReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> RecordingReader<R> {
+    /// Fails with `InvalidRecording` if the file header doesn't match
+    pub fn new(mut reader: R) -> Result<Self, F1Error> {
+        let version = read_file_header(&mut reader)?;
+
+        Ok(RecordingReader {
+            reader,
+            version,
+            offset: FILE_HEADER_SIZE as u64,
+        })
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Position of the next record, in bytes from the start of the recording
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns `None` at the end of the recording
+    /// A record cut short, e.g. by a crash while recording, is an `IncompleteData` error
+    pub fn next_record(&mut self) -> Result<Option<Record>, F1Error> {
+        let mut size = [0u8; 4];
+
+        match read_full(&mut self.reader, &mut size)? {
+            0 => return Ok(None),
+            4 => {}
+            _ => return Err(F1Error::IncompleteData),
+        }
+
+        let size = u32::from_le_bytes(size) as usize;
+
+        if size > MAX_RECORD_SIZE {
+            return Err(F1Error::InvalidRecording);
+        }
+
+        let mut record = vec![0u8; size];
+
+        if read_full(&mut self.reader, &mut record)? < size {
+            return Err(F1Error::IncompleteData);
+        }
+
+        self.offset += 4 + size as u64;
+
+        parse_record(&record).map(Some)
+    }
+
+    /// Iterates over the raw records
+    pub fn records(self) -> Records<R> {
+        Records { reader: self }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read + Seek> RecordingReader<R> {
+    /// Moves to a record position returned by `offset`
+    /// The recording has to start at the beginning of the reader
+    pub fn seek_to(&mut self, offset: u64) -> Result<(), F1Error> {
+        self.reader.seek(SeekFrom::Start(offset))?;
+        self.offset = offset;
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for RecordingReader<R> {
+    type Item = Result<(SystemTime, Packet), F1Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_record() {
+            Ok(Some(record)) => Some(record.packet().map(|packet| (record.timestamp, packet))),
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Iterator over the raw records of a recording
+pub struct Records<R: Read> {
+    reader: RecordingReader<R>,
+}
+
+impl<R: Read> Iterator for Records<R> {
+    type Item = Result<Record, F1Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next_record().transpose()
+    }
+}
+
+fn read_file_header(reader: &mut impl Read) -> Result<u16, F1Error> {
+    let mut header = [0u8; FILE_HEADER_SIZE];
+
+    if read_full(reader, &mut header)? < FILE_HEADER_SIZE || header[..8] != MAGIC {
+        return Err(F1Error::InvalidRecording);
+    }
+
+    let version = u16::from_le_bytes([header[8], header[9]]);
+
+    if version != VERSION {
+        return Err(F1Error::InvalidRecording);
+    }
+
+    Ok(version)
+}
+
+/// Returns the offset after the last record whose length prefix and data are complete
+fn complete_records_end(reader: &mut impl Read) -> Result<u64, F1Error> {
+    read_file_header(reader)?;
+
+    let mut end = FILE_HEADER_SIZE as u64;
+    let mut size = [0u8; 4];
+
+    loop {
+        if read_full(reader, &mut size)? < size.len() {
+            return Ok(end);
+        }
+
+        let size = u32::from_le_bytes(size) as u64;
+
+        if size > MAX_RECORD_SIZE as u64
+            || io::copy(&mut reader.take(size), &mut io::sink())? < size
+        {
+            return Ok(end);
+        }
+
+        end += 4 + size;
+    }
+}
+
+fn parse_record(mut buf: &[u8]) -> Result<Record, F1Error> {
+    if buf.remaining() < RECORD_FIXED_SIZE {
+        return Err(F1Error::IncompleteData);
+    }
+
+    let timestamp = UNIX_EPOCH
+        .checked_add(Duration::from_micros(buf.get_u64_le()))
+        .ok_or(F1Error::InvalidRecording)?;
+
+    let ip = match buf.get_u8() {
+        4 if buf.remaining() >= 4 + 2 => {
+            let mut octets = [0u8; 4];
+            buf.copy_to_slice(&mut octets);
+            IpAddr::V4(Ipv4Addr::from(octets))
+        }
+        6 if buf.remaining() >= 16 + 2 => {
+            let mut octets = [0u8; 16];
+            buf.copy_to_slice(&mut octets);
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        4 | 6 => return Err(F1Error::IncompleteData),
+        _ => return Err(F1Error::InvalidRecording),
+    };
+
+    let port = buf.get_u16_le();
+
+    Ok(Record {
+        timestamp,
+        source: SocketAddr::new(ip, port),
+        datagram: buf.to_vec(),
+    })
+}
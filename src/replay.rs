@@ -0,0 +1,293 @@
+//! Re-sends a recording over UDP, so a receiver sees the datagrams as the game sent them
+
+use std::{
+    fs::File,
+    io::{BufReader, Cursor, Read, Seek},
+    net::{SocketAddr, UdpSocket},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::error::F1Error;
+use crate::packet::header::parse_header;
+use crate::recording::{Record, Recorder, RecordingReader};
+
+/// Longest sleep between checks for pause, stop and seek requests
+const CONTROL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// Same timing as the recording
+    RealTime,
+    /// Timing multiplied by the factor, 2.0 plays twice as fast
+    /// The factor has to be finite and greater than 0
+    Scaled(f64),
+    /// No waiting between datagrams
+    AsFastAsPossible,
+}
+
+impl ReplaySpeed {
+    /// When a record `offset` after the first one is sent, relative to the start of the replay
+    /// Saturates at `Duration::MAX` for factors too small to represent the result
+    pub fn scale(&self, offset: Duration) -> Duration {
+        match self {
+            ReplaySpeed::RealTime => offset,
+            ReplaySpeed::Scaled(factor) => {
+                Duration::try_from_secs_f64(offset.as_secs_f64() / factor).unwrap_or(Duration::MAX)
+            }
+            ReplaySpeed::AsFastAsPossible => Duration::ZERO,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeekTarget {
+    /// First packet with a header session time greater or equal to this one
+    SessionTime(f32),
+    /// First packet with a header frame identifier greater or equal to this one
+    FrameIdentifier(u32),
+}
+
+/// Controls a running `Replayer` from another thread
+#[derive(Debug, Clone, Default)]
+pub struct ReplayHandle {
+    paused: Arc<AtomicBool>,
+    stopped: Arc<AtomicBool>,
+    seek: Arc<Mutex<Option<SeekTarget>>>,
+}
+
+impl ReplayHandle {
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Makes `Replayer::run` return before the end of the recording
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+
+    /// Moves the running replay, timing restarts from the new position
+    pub fn seek(&self, target: SeekTarget) {
+        *self.seek.lock().unwrap() = Some(target);
+    }
+}
+
+/// Position and header fields of a record, so seeking doesn't need to read the recording again
+#[derive(Debug, Clone, Copy)]
+struct IndexEntry {
+    offset: u64,
+    /// Session time and frame identifier, `None` if the datagram has no valid header
+    header: Option<(f32, u32)>,
+}
+
+/// Sends the datagrams of a recording to a target address
+/// Records are read from the recording as they are sent, only a small index is kept in memory
+pub struct Replayer<R: Read + Seek = BufReader<File>> {
+    reader: RecordingReader<R>,
+    index: Vec<IndexEntry>,
+    socket: UdpSocket,
+    target: SocketAddr,
+    speed: ReplaySpeed,
+    position: usize,
+    handle: ReplayHandle,
+}
+
+impl Replayer<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>, target: SocketAddr) -> Result<Self, F1Error> {
+        Replayer::from_reader(RecordingReader::open(path)?, target)
+    }
+}
+
+impl Replayer<Cursor<Vec<u8>>> {
+    /// Replays records kept in memory
+    pub fn from_records(records: Vec<Record>, target: SocketAddr) -> Result<Self, F1Error> {
+        let mut recorder = Recorder::new(Vec::new())?;
+
+        for record in records {
+            recorder.record(record.timestamp, record.source, &record.datagram)?;
+        }
+
+        let reader = RecordingReader::new(Cursor::new(recorder.into_inner()))?;
+
+        Replayer::from_reader(reader, target)
+    }
+}
+
+impl<R: Read + Seek> Replayer<R> {
+    /// Reads the recording once to index its records
+    /// The recording has to start at the beginning of the reader. A record cut short at the end,
+    /// e.g. by a crash while recording, is left out like `Recorder::append` does
+    pub fn from_reader(
+        mut reader: RecordingReader<R>,
+        target: SocketAddr,
+    ) -> Result<Self, F1Error> {
+        let mut index = Vec::new();
+
+        loop {
+            let offset = reader.offset();
+
+            let record = match reader.next_record() {
+                Ok(Some(record)) => record,
+                Ok(None) => break,
+                // The offset only stays put when the end of the recording was reached mid-record
+                Err(F1Error::IncompleteData) if reader.offset() == offset => break,
+                Err(err) => return Err(err),
+            };
+
+            let header = parse_header(&mut &record.datagram[..])
+                .ok()
+                .map(|header| (header.session_time, header.frame_identifier));
+
+            index.push(IndexEntry { offset, header });
+        }
+
+        let local_address: SocketAddr = if target.is_ipv4() {
+            ([0, 0, 0, 0], 0).into()
+        } else {
+            ([0u16; 8], 0).into()
+        };
+
+        Ok(Replayer {
+            reader,
+            index,
+            socket: UdpSocket::bind(local_address)?,
+            target,
+            speed: ReplaySpeed::RealTime,
+            position: 0,
+            handle: ReplayHandle::default(),
+        })
+    }
+
+    /// Fails with `ConversionError` for a scale factor that isn't finite and greater than 0
+    pub fn set_speed(&mut self, speed: ReplaySpeed) -> Result<(), F1Error> {
+        if let ReplaySpeed::Scaled(factor) = speed {
+            if !factor.is_finite() || factor <= 0.0 {
+                return Err(F1Error::ConversionError);
+            }
+        }
+
+        self.speed = speed;
+
+        Ok(())
+    }
+
+    pub fn handle(&self) -> ReplayHandle {
+        self.handle.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Index of the next record to send
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves to the first matching record, returns `false` and moves to the end if there is none
+    /// Records that don't start with a valid header are skipped
+    pub fn seek(&mut self, target: SeekTarget) -> bool {
+        let found = self
+            .index
+            .iter()
+            .position(|entry| match (entry.header, target) {
+                (Some((session_time, _)), SeekTarget::SessionTime(time)) => session_time >= time,
+                (Some((_, frame_identifier)), SeekTarget::FrameIdentifier(frame)) => {
+                    frame_identifier >= frame
+                }
+                (None, _) => false,
+            });
+
+        self.position = found.unwrap_or(self.index.len());
+
+        found.is_some()
+    }
+
+    /// Sends the records from the current position until the end of the recording
+    /// or until `ReplayHandle::stop` is called
+    pub fn run(&mut self) -> Result<(), F1Error> {
+        // Time the replay was started or resumed at, with the timestamp of the record sent then
+        let mut anchor: Option<(Instant, SystemTime)> = None;
+        // Record at the current position, kept while waiting to send it
+        let mut next: Option<Record> = None;
+
+        while self.position < self.index.len() {
+            if self.handle.stopped.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let pending_seek = self.handle.seek.lock().unwrap().take();
+
+            if let Some(target) = pending_seek {
+                self.seek(target);
+                anchor = None;
+                next = None;
+                continue;
+            }
+
+            if self.handle.is_paused() {
+                thread::sleep(CONTROL_INTERVAL);
+                anchor = None;
+                continue;
+            }
+
+            let record = match next.take() {
+                Some(record) => record,
+                None => self.read_record()?,
+            };
+
+            let (started, first_timestamp) =
+                *anchor.get_or_insert((Instant::now(), record.timestamp));
+
+            let offset = self.speed.scale(
+                record
+                    .timestamp
+                    .duration_since(first_timestamp)
+                    .unwrap_or_default(),
+            );
+
+            let remaining = started.checked_add(offset).map_or(CONTROL_INTERVAL, |due| {
+                due.saturating_duration_since(Instant::now())
+            });
+
+            if !remaining.is_zero() {
+                next = Some(record);
+                thread::sleep(remaining.min(CONTROL_INTERVAL));
+                continue;
+            }
+
+            self.socket.send_to(&record.datagram, self.target)?;
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the record at the current position, seeking only if the last read was elsewhere
+    fn read_record(&mut self) -> Result<Record, F1Error> {
+        let offset = self.index[self.position].offset;
+
+        if self.reader.offset() != offset {
+            self.reader.seek_to(offset)?;
+        }
+
+        self.reader.next_record()?.ok_or(F1Error::IncompleteData)
+    }
+}
@@ -0,0 +1,263 @@
+//! Groups the packets the game sends for the same frame into a single `FrameSnapshot`
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::packet::car_damage::DamageData;
+use crate::packet::car_setups::CarSetupsData;
+use crate::packet::car_status::StatusData;
+use crate::packet::car_telemetry::TelemetryData;
+use crate::packet::event::EventData;
+use crate::packet::final_classification::FinalClassificationData;
+use crate::packet::header::Header;
+use crate::packet::lap_data::LapData;
+use crate::packet::lobby_info::LobbyData;
+use crate::packet::motion::MotionData;
+use crate::packet::participants::ParticipantsData;
+use crate::packet::session::SessionData;
+use crate::packet::session_history::SessionHistoryData;
+use crate::packet::{Packet, PacketType};
+
+#[cfg(feature = "tokio")]
+pub use self::stream::FrameSnapshots;
+
+/// Number of emitted frames `FrameAggregator` remembers to recognize late packets
+pub const RECENT_FRAMES: usize = 8;
+
+/// Every packet received for one `session_uid` and `frame_identifier`
+/// A packet type received twice in the same frame keeps the latest one
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameSnapshot {
+    pub session_uid: u64,
+    pub frame_identifier: u32,
+    /// Session time of the latest packet of the frame
+    pub session_time: f32,
+    pub player_car_index: u8,
+    pub secondary_player_car_index: u8,
+    pub motion: Option<MotionData>,
+    pub session: Option<SessionData>,
+    pub lap_data: Option<LapData>,
+    /// Several events can happen in the same frame
+    pub events: Vec<EventData>,
+    pub participants: Option<ParticipantsData>,
+    pub car_setups: Option<CarSetupsData>,
+    pub car_telemetry: Option<TelemetryData>,
+    pub car_status: Option<StatusData>,
+    pub final_classification: Option<FinalClassificationData>,
+    pub lobby_info: Option<LobbyData>,
+    pub car_damage: Option<DamageData>,
+    /// Session history is sent for one car at a time
+    pub session_history: Vec<SessionHistoryData>,
+}
+
+impl FrameSnapshot {
+    fn new(header: &Header) -> Self {
+        FrameSnapshot {
+            session_uid: header.session_uid,
+            frame_identifier: header.frame_identifier,
+            session_time: header.session_time,
+            player_car_index: header.player_car_index,
+            secondary_player_car_index: header.secondary_player_car_index,
+            motion: None,
+            session: None,
+            lap_data: None,
+            events: Vec::new(),
+            participants: None,
+            car_setups: None,
+            car_telemetry: None,
+            car_status: None,
+            final_classification: None,
+            lobby_info: None,
+            car_damage: None,
+            session_history: Vec::new(),
+        }
+    }
+
+    /// Whether the packet was sent for this snapshot's frame
+    pub fn is_same_frame(&self, header: &Header) -> bool {
+        self.session_uid == header.session_uid && self.frame_identifier == header.frame_identifier
+    }
+
+    #[allow(deprecated)]
+    fn insert(&mut self, packet: Packet) {
+        self.session_time = packet.header.session_time;
+
+        match packet.data {
+            PacketType::Motion(data) => self.motion = Some(data),
+            PacketType::Session(data) => self.session = Some(data),
+            PacketType::LapData(data) => self.lap_data = Some(data),
+            PacketType::Event(data) => self.events.push(data),
+            PacketType::Participants(data) => self.participants = Some(data),
+            PacketType::CarSetups(data) => self.car_setups = Some(data),
+            PacketType::CarTelemetry(data) => self.car_telemetry = Some(data),
+            PacketType::CarStatus(data) => self.car_status = Some(data),
+            PacketType::FinalClassification(data) => self.final_classification = Some(data),
+            PacketType::LobbyInfo(data) => self.lobby_info = Some(data),
+            PacketType::CarDamage(data) => self.car_damage = Some(data),
+            PacketType::SessionHistory(data) => self.session_history.push(data),
+            PacketType::Unimplemented => {}
+        }
+    }
+}
+
+/// Builds `FrameSnapshot`s from packets pushed in the order they were received
+/// Packets arriving after their frame was emitted are dropped and counted in `late_packets`,
+/// so none of the last `RECENT_FRAMES` emitted frames is emitted twice.
+/// A flashback to one of those frames drops its packets the same way
+#[derive(Debug, Clone, Default)]
+pub struct FrameAggregator {
+    pending: Option<FrameSnapshot>,
+    /// Session uid and frame identifier of the last emitted frames, the latest at the back
+    recent: VecDeque<(u64, u32)>,
+    late_packets: u64,
+}
+
+impl FrameAggregator {
+    pub fn new() -> Self {
+        FrameAggregator::default()
+    }
+
+    /// Returns the previous frame's snapshot when the packet belongs to another frame
+    /// Any change of frame counts, the game goes back to earlier frames after a flashback
+    pub fn push(&mut self, packet: Packet) -> Option<FrameSnapshot> {
+        let frame = (packet.header.session_uid, packet.header.frame_identifier);
+
+        let completed = match &self.pending {
+            Some(pending) if pending.is_same_frame(&packet.header) => None,
+            _ if self.recent.contains(&frame) => {
+                self.late_packets += 1;
+                return None;
+            }
+            _ => self.pending.replace(FrameSnapshot::new(&packet.header)),
+        };
+
+        if let Some(pending) = &mut self.pending {
+            pending.insert(packet);
+        }
+
+        completed.map(|snapshot| self.emit(snapshot))
+    }
+
+    /// The snapshot of the frame that is still receiving packets
+    pub fn pending(&self) -> Option<&FrameSnapshot> {
+        self.pending.as_ref()
+    }
+
+    /// Emits the pending frame without waiting for the next one
+    pub fn flush(&mut self) -> Option<FrameSnapshot> {
+        self.pending.take().map(|snapshot| self.emit(snapshot))
+    }
+
+    /// Number of packets dropped because their frame was already emitted
+    pub fn late_packets(&self) -> u64 {
+        self.late_packets
+    }
+
+    fn emit(&mut self, snapshot: FrameSnapshot) -> FrameSnapshot {
+        if self.recent.len() == RECENT_FRAMES {
+            self.recent.pop_front();
+        }
+
+        self.recent
+            .push_back((snapshot.session_uid, snapshot.frame_identifier));
+
+        snapshot
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod stream {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use std::time::Duration;
+
+    use tokio::time::{Instant, Sleep};
+    use tokio_stream::Stream;
+
+    use super::{FrameAggregator, FrameSnapshot};
+    use crate::error::F1Error;
+    use crate::packet::Packet;
+
+    /// Stream of `FrameSnapshot`s built from a stream of packets, like `F1_2021::telemetry`
+    /// A frame is emitted when a packet of another frame arrives, or once `timeout` has passed
+    /// since its first packet. Packets still arriving for a frame emitted by the timeout are
+    /// dropped, like in `FrameAggregator`. Errors are passed through and the pending frame is
+    /// emitted when the packet stream ends.
+    pub struct FrameSnapshots<S> {
+        packets: S,
+        aggregator: FrameAggregator,
+        timeout: Duration,
+        deadline: Pin<Box<Sleep>>,
+        finished: bool,
+    }
+
+    impl<S> FrameSnapshots<S>
+    where
+        S: Stream<Item = Result<Packet, F1Error>> + Unpin,
+    {
+        pub fn new(packets: S, timeout: Duration) -> Self {
+            FrameSnapshots {
+                packets,
+                aggregator: FrameAggregator::new(),
+                timeout,
+                deadline: Box::pin(tokio::time::sleep(timeout)),
+                finished: false,
+            }
+        }
+
+        /// Number of packets dropped because their frame was already emitted
+        pub fn late_packets(&self) -> u64 {
+            self.aggregator.late_packets()
+        }
+
+        pub fn into_inner(self) -> S {
+            self.packets
+        }
+    }
+
+    impl<S> Stream for FrameSnapshots<S>
+    where
+        S: Stream<Item = Result<Packet, F1Error>> + Unpin,
+    {
+        type Item = Result<FrameSnapshot, F1Error>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            while !this.finished {
+                match Pin::new(&mut this.packets).poll_next(cx) {
+                    Poll::Ready(Some(Ok(packet))) => {
+                        let had_pending = this.aggregator.pending().is_some();
+                        let completed = this.aggregator.push(packet);
+
+                        // The packet started a new frame, late packets are dropped without one
+                        if completed.is_some()
+                            || (!had_pending && this.aggregator.pending().is_some())
+                        {
+                            this.deadline.as_mut().reset(Instant::now() + this.timeout);
+                        }
+
+                        if let Some(snapshot) = completed {
+                            return Poll::Ready(Some(Ok(snapshot)));
+                        }
+                    }
+                    Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                    Poll::Ready(None) => this.finished = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            if this.finished {
+                return Poll::Ready(this.aggregator.flush().map(Ok));
+            }
+
+            if this.aggregator.pending().is_some() && this.deadline.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(this.aggregator.flush().map(Ok));
+            }
+
+            Poll::Pending
+        }
+    }
+}
@@ -1,10 +1,14 @@
-use std::io::Cursor;
+use alloc::{string::String, vec};
+use bytes::{Buf, BufMut, BytesMut};
 
-use bytes::{Buf, BytesMut};
+use crate::error::F1Error;
 
 pub const NUMBER_OF_CARS: usize = 22;
+pub const NAME_SIZE: usize = 48;
+pub const MAX_TYRE_STINTS: usize = 8;
 
 #[derive(Debug, Clone, PartialEq, Default, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinates3D<T> {
     pub x: T,
     pub y: T,
@@ -12,6 +16,7 @@ pub struct Coordinates3D<T> {
 }
 
 #[derive(Debug, Clone, PartialEq, Default, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WheelsData<T> {
     pub rear_left: T,
     pub rear_right: T,
@@ -19,7 +24,30 @@ pub struct WheelsData<T> {
     pub front_right: T,
 }
 
-pub(crate) fn parse_coordinates_3d_f32(buf: &mut Cursor<&mut BytesMut>) -> Coordinates3D<f32> {
+impl<T> WheelsData<T> {
+    /// Applies `f` to every wheel
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> WheelsData<U> {
+        WheelsData {
+            rear_left: f(self.rear_left),
+            rear_right: f(self.rear_right),
+            front_left: f(self.front_left),
+            front_right: f(self.front_right),
+        }
+    }
+
+    /// Iterates in rear left, rear right, front left, front right order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        [
+            &self.rear_left,
+            &self.rear_right,
+            &self.front_left,
+            &self.front_right,
+        ]
+        .into_iter()
+    }
+}
+
+pub(crate) fn parse_coordinates_3d_f32(buf: &mut impl Buf) -> Coordinates3D<f32> {
     Coordinates3D {
         x: buf.get_f32_le(),
         y: buf.get_f32_le(),
@@ -27,7 +55,7 @@ pub(crate) fn parse_coordinates_3d_f32(buf: &mut Cursor<&mut BytesMut>) -> Coord
     }
 }
 
-pub(crate) fn parse_coordinates_3d_i16(buf: &mut Cursor<&mut BytesMut>) -> Coordinates3D<i16> {
+pub(crate) fn parse_coordinates_3d_i16(buf: &mut impl Buf) -> Coordinates3D<i16> {
     Coordinates3D {
         x: buf.get_i16_le(),
         y: buf.get_i16_le(),
@@ -35,7 +63,7 @@ pub(crate) fn parse_coordinates_3d_i16(buf: &mut Cursor<&mut BytesMut>) -> Coord
     }
 }
 
-pub(crate) fn parse_wheels_data_f32(buf: &mut Cursor<&mut BytesMut>) -> WheelsData<f32> {
+pub(crate) fn parse_wheels_data_f32(buf: &mut impl Buf) -> WheelsData<f32> {
     WheelsData {
         rear_left: buf.get_f32_le(),
         rear_right: buf.get_f32_le(),
@@ -44,7 +72,7 @@ pub(crate) fn parse_wheels_data_f32(buf: &mut Cursor<&mut BytesMut>) -> WheelsDa
     }
 }
 
-pub(crate) fn parse_wheels_data_u16(buf: &mut Cursor<&mut BytesMut>) -> WheelsData<u16> {
+pub(crate) fn parse_wheels_data_u16(buf: &mut impl Buf) -> WheelsData<u16> {
     WheelsData {
         rear_left: buf.get_u16_le(),
         rear_right: buf.get_u16_le(),
@@ -53,7 +81,7 @@ pub(crate) fn parse_wheels_data_u16(buf: &mut Cursor<&mut BytesMut>) -> WheelsDa
     }
 }
 
-pub(crate) fn parse_wheels_data_u8(buf: &mut Cursor<&mut BytesMut>) -> WheelsData<u8> {
+pub(crate) fn parse_wheels_data_u8(buf: &mut impl Buf) -> WheelsData<u8> {
     WheelsData {
         rear_left: buf.get_u8(),
         rear_right: buf.get_u8(),
@@ -61,3 +89,76 @@ pub(crate) fn parse_wheels_data_u8(buf: &mut Cursor<&mut BytesMut>) -> WheelsDat
         front_right: buf.get_u8(),
     }
 }
+
+/// Parses a fixed width, null terminated UTF-8 string
+pub(crate) fn parse_string(buf: &mut impl Buf, size: usize) -> Result<String, F1Error> {
+    let mut bytes = vec![0u8; size];
+    buf.copy_to_slice(&mut bytes);
+
+    let end = bytes.iter().position(|&c| c == 0).unwrap_or(size);
+
+    Ok(String::from(core::str::from_utf8(&bytes[..end])?))
+}
+
+pub(crate) fn write_coordinates_3d_f32(buf: &mut BytesMut, data: &Coordinates3D<f32>) {
+    buf.put_f32_le(data.x);
+    buf.put_f32_le(data.y);
+    buf.put_f32_le(data.z);
+}
+
+pub(crate) fn write_coordinates_3d_i16(buf: &mut BytesMut, data: &Coordinates3D<i16>) {
+    buf.put_i16_le(data.x);
+    buf.put_i16_le(data.y);
+    buf.put_i16_le(data.z);
+}
+
+pub(crate) fn write_wheels_data_f32(buf: &mut BytesMut, data: &WheelsData<f32>) {
+    buf.put_f32_le(data.rear_left);
+    buf.put_f32_le(data.rear_right);
+    buf.put_f32_le(data.front_left);
+    buf.put_f32_le(data.front_right);
+}
+
+pub(crate) fn write_wheels_data_u16(buf: &mut BytesMut, data: &WheelsData<u16>) {
+    buf.put_u16_le(data.rear_left);
+    buf.put_u16_le(data.rear_right);
+    buf.put_u16_le(data.front_left);
+    buf.put_u16_le(data.front_right);
+}
+
+pub(crate) fn write_wheels_data_u8(buf: &mut BytesMut, data: &WheelsData<u8>) {
+    buf.put_u8(data.rear_left);
+    buf.put_u8(data.rear_right);
+    buf.put_u8(data.front_left);
+    buf.put_u8(data.front_right);
+}
+
+/// Writes a fixed width, null terminated UTF-8 string
+/// Strings that don't fit are cut at the last whole character
+pub(crate) fn write_string(buf: &mut BytesMut, string: &str, size: usize) {
+    let mut end = string.len().min(size - 1);
+
+    while !string.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    buf.put_slice(&string.as_bytes()[..end]);
+    buf.put_bytes(0, size - end);
+}
+
+/// Like `read_exact`, but returns how many bytes were read before the end of the data
+#[cfg(feature = "std")]
+pub(crate) fn read_full(reader: &mut impl std::io::Read, buf: &mut [u8]) -> Result<usize, F1Error> {
+    let mut read = 0;
+
+    while read < buf.len() {
+        match reader.read(&mut buf[read..]) {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(read)
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests, each test only uses some of them
+#![allow(dead_code)]
+
+use std::fs::File;
+use std::io::Read;
+use std::net::{SocketAddr, UdpSocket};
+
+use f1_2021_telemetry::packet::Packet;
+
+pub fn read_sample(name: &str) -> Vec<u8> {
+    let mut f = File::open(format!("tests/packet_samples/{}", name)).expect("no file found");
+    let mut buf = Vec::new();
+    f.read_to_end(&mut buf).expect("can't read file");
+
+    buf
+}
+
+pub fn parse_sample(name: &str) -> Packet {
+    Packet::parse(&read_sample(name)).unwrap()
+}
+
+/// Address of a port that was free when the function returned
+pub fn free_local_address() -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    socket.local_addr().unwrap()
+}
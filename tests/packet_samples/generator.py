@@ -7,10 +7,38 @@ packet_motion_data = struct.Struct("<4f4f4f4f4fffffffffff")
 packet_event_data = struct.Struct("<4B")
 fastest_lap = struct.Struct("<Bf")
 buttons = struct.Struct("<I")
+penalty = struct.Struct("<7B")
 
 car_telemetry_data = struct.Struct("<H3fBbHBBH4H4B4BH4f4B")
 packet_car_telemetry_data = struct.Struct("<BBb")
 
+packet_session_data = struct.Struct("<BbbBHBbBHHBBBBBB")
+marshal_zone = struct.Struct("<fb")
+packet_session_data_forecast = struct.Struct("<BBB")
+weather_forecast_sample = struct.Struct("<BBBbbbbB")
+packet_session_data_end = struct.Struct("<BBIIIBBBBBBBBBBBB")
+
+lap_data = struct.Struct("<IIHHfffBBBBBBBBBBBBBBHHB")
+
+packet_participants_data = struct.Struct("<B")
+participant_data = struct.Struct("<BBBBBBB48sB")
+
+car_setup_data = struct.Struct("<BBBBffffBBBBBBBB4fBf")
+
+car_status_data = struct.Struct("<BBBBBfffHHBBHBBBbfBfffB")
+
+packet_final_classification_data = struct.Struct("<B")
+final_classification_data = struct.Struct("<BBBBBBIdBBB8B8B")
+
+packet_lobby_info_data = struct.Struct("<B")
+lobby_info_data = struct.Struct("<BBB48sBB")
+
+car_damage_data = struct.Struct("<4f4B4BBBBBBBBBBBBBBBB")
+
+packet_session_history_data = struct.Struct("<BBBBBBB")
+lap_history_data = struct.Struct("<IHHHB")
+tyre_stint_history_data = struct.Struct("<BBB")
+
 
 def write_motion_packet():
     packed_header = header.pack(2021, 1, 2, 1, 0, 1, 12.35, 123, 1, 255)
@@ -39,6 +67,140 @@ def write_telemetry_packet():
         f.write(packed_car_telemetry)
 
 
+def write_session_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 1, 1, 12.35, 123, 1, 255)
+    packed_session_data = packet_session_data.pack(
+        1, 30, 25, 5, 5300, 10, 0, 0, 3600, 7200, 80, 0, 0, 255, 0, 3)
+    packed_marshal_zone = marshal_zone.pack(0.5, 1)
+    packed_session_forecast = packet_session_data_forecast.pack(0, 0, 2)
+    packed_forecast_sample = weather_forecast_sample.pack(
+        10, 5, 1, 31, 0, 24, 2, 10)
+    packed_session_end = packet_session_data_end.pack(
+        0, 90, 1, 2, 3, 10, 15, 5, 0, 1, 3, 1, 0, 0, 1, 2, 1)
+
+    with open("session.pkt", "wb") as f:
+        f.write(packed_header)
+        f.write(packed_session_data)
+        for _ in range(21):
+            f.write(packed_marshal_zone)
+        f.write(packed_session_forecast)
+        for _ in range(56):
+            f.write(packed_forecast_sample)
+        f.write(packed_session_end)
+
+
+def write_lap_data_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 2, 1, 12.35, 123, 1, 255)
+    packed_lap_data = lap_data.pack(
+        90500, 30250, 29000, 31000, 1500.5, 7000.5, 0.0, 3, 2, 0, 1, 1, 0, 5, 1, 0, 0, 4, 4, 2, 0, 0, 0, 0)
+
+    with open("lap_data.pkt", "wb") as f:
+        f.write(packed_header)
+        for _ in range(22):
+            f.write(packed_lap_data)
+
+
+def write_participants_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 4, 1, 12.35, 123, 1, 255)
+    packed_participants_data = packet_participants_data.pack(20)
+    packed_participant = participant_data.pack(
+        1, 7, 0, 0, 0, 44, 10, "Lewis Hamilton".encode("utf-8"), 1)
+
+    with open("participants.pkt", "wb") as f:
+        f.write(packed_header)
+        f.write(packed_participants_data)
+        for _ in range(22):
+            f.write(packed_participant)
+
+
+def write_car_setups_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 5, 1, 12.35, 123, 1, 255)
+    packed_car_setup = car_setup_data.pack(
+        5, 6, 70, 55, -3.0, -1.5, 0.0625, 0.25, 5, 4, 6, 3, 3, 6, 100, 56, 22.5, 22.5, 23.5, 23.5, 0, 5.5)
+
+    with open("car_setups.pkt", "wb") as f:
+        f.write(packed_header)
+        for _ in range(22):
+            f.write(packed_car_setup)
+
+
+def write_car_status_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 7, 1, 12.35, 123, 1, 255)
+    packed_car_status = car_status_data.pack(
+        2, 1, 1, 56, 0, 50.5, 110.0, 12.25, 13000, 4000, 8, 1, 0, 18, 16, 3, 1, 2000000.0, 1, 10000.0, 20000.0, 30000.0, 0)
+
+    with open("car_status.pkt", "wb") as f:
+        f.write(packed_header)
+        for _ in range(22):
+            f.write(packed_car_status)
+
+
+def write_final_classification_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 8, 1, 12.35, 123, 1, 255)
+    packed_final_classification_data = packet_final_classification_data.pack(
+        20)
+    packed_final_classification = final_classification_data.pack(
+        1, 58, 2, 25, 2, 3, 85123, 5400.5, 5, 1, 3, 18, 17, 18, 0, 0, 0, 0, 0, 16, 17, 16, 0, 0, 0, 0, 0)
+
+    with open("final_classification.pkt", "wb") as f:
+        f.write(packed_header)
+        f.write(packed_final_classification_data)
+        for _ in range(22):
+            f.write(packed_final_classification)
+
+
+def write_lobby_info_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 9, 1, 12.35, 123, 1, 255)
+    packed_lobby_info_data = packet_lobby_info_data.pack(2)
+    packed_lobby_info = lobby_info_data.pack(
+        0, 2, 82, "Sérgio Pérez".encode("utf-8"), 11, 1)
+
+    with open("lobby_info.pkt", "wb") as f:
+        f.write(packed_header)
+        f.write(packed_lobby_info_data)
+        for _ in range(22):
+            f.write(packed_lobby_info)
+
+
+def write_car_damage_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 10, 1, 12.35, 123, 1, 255)
+    packed_car_damage = car_damage_data.pack(
+        10.5, 11.5, 8.25, 8.75, 1, 1, 0, 0, 2, 2, 3, 3, 15, 0, 5, 0, 0, 0, 1, 4, 6, 12, 13, 14, 15, 16, 17)
+
+    with open("car_damage.pkt", "wb") as f:
+        f.write(packed_header)
+        for _ in range(22):
+            f.write(packed_car_damage)
+
+
+def write_session_history_packet():
+    packed_header = header.pack(2021, 1, 2, 1, 11, 1, 12.35, 123, 1, 255)
+    packed_session_history_data = packet_session_history_data.pack(
+        1, 2, 2, 1, 2, 1, 1)
+    packed_laps = [
+        lap_history_data.pack(90000, 30000, 30000, 30000, 0x0F),
+        lap_history_data.pack(91000, 31000, 29000, 31000, 0x0B),
+    ]
+    packed_empty_lap = lap_history_data.pack(0, 0, 0, 0, 0)
+    packed_stints = [
+        tyre_stint_history_data.pack(1, 18, 16),
+        tyre_stint_history_data.pack(255, 17, 17),
+    ]
+    packed_empty_stint = tyre_stint_history_data.pack(0, 0, 0)
+
+    with open("session_history.pkt", "wb") as f:
+        f.write(packed_header)
+        f.write(packed_session_history_data)
+        for lap in packed_laps:
+            f.write(lap)
+        for _ in range(100 - len(packed_laps)):
+            f.write(packed_empty_lap)
+        for stint in packed_stints:
+            f.write(stint)
+        for _ in range(8 - len(packed_stints)):
+            f.write(packed_empty_stint)
+
+
 def write_header():
     packed_header = header.pack(2021, 1, 2, 1, 0, 1, 12.35, 123, 1, 255)
 
@@ -70,6 +232,19 @@ def write_event_packet_BUTN():
         f.write(packed_buttons)
 
 
+def write_event_packet_PENA():
+    packed_header = header.pack(2021, 1, 2, 1, 3, 1, 12.35, 123, 1, 255)
+    packed_event_data = packet_event_data.pack(
+        ord('P'), ord('E'), ord('N'), ord('A'))
+    # Time penalty for corner cutting gained time
+    packed_penalty = penalty.pack(4, 7, 3, 255, 5, 2, 0)
+
+    with open("event_pena.pkt", "wb") as f:
+        f.write(packed_header)
+        f.write(packed_event_data)
+        f.write(packed_penalty)
+
+
 def write_event_packet_SSTA():
     packed_header = header.pack(2021, 1, 2, 1, 3, 1, 12.35, 123, 1, 255)
     packed_event_data = packet_event_data.pack(
@@ -86,4 +261,14 @@ if __name__ == "__main__":
     # write_event_packet_FTLP()
     # write_event_packet_BUTN()
     # write_event_packet_SSTA()
-    write_telemetry_packet()
+    # write_event_packet_PENA()
+    # write_telemetry_packet()
+    # write_session_packet()
+    # write_lap_data_packet()
+    write_participants_packet()
+    # write_car_setups_packet()
+    # write_car_status_packet()
+    # write_final_classification_packet()
+    # write_lobby_info_packet()
+    # write_car_damage_packet()
+    # write_session_history_packet()
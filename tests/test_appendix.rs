@@ -0,0 +1,70 @@
+use f1_2021_telemetry::packet::appendix::{Driver, Nationality, Team, Track};
+use f1_2021_telemetry::packet::PacketType;
+
+mod common;
+
+use common::parse_sample;
+
+#[test]
+fn test_team() {
+    assert_eq!(Team::from(2), Team::RedBullRacing);
+    assert_eq!(Team::RedBullRacing.to_string(), "Red Bull Racing");
+    assert_eq!(Team::RedBullRacing.short_code(), Some("RBR"));
+    assert_eq!(Team::from(106), Team::Prema21);
+    assert_eq!(Team::Prema21.to_string(), "Prema '21");
+    assert_eq!(Team::from(105), Team::Unknown(105));
+    assert_eq!(Team::Unknown(105).short_code(), None);
+}
+
+#[test]
+fn test_driver() {
+    assert_eq!(Driver::from(7), Driver::LewisHamilton);
+    assert_eq!(Driver::from(6).to_string(), "Kimi Räikkönen");
+    assert_eq!(Driver::from(255), Driver::Unknown(255));
+    assert_eq!(Driver::Unknown(255).to_string(), "Unknown (255)");
+}
+
+#[test]
+fn test_track() {
+    assert_eq!(Track::from(29), Track::Jeddah);
+    assert_eq!(Track::from(28).to_string(), "Portimão");
+    assert_eq!(Track::Melbourne.short_code(), Some("AUS"));
+    assert_eq!(Track::from(30), Track::Unknown(30));
+}
+
+#[test]
+fn test_nationality() {
+    assert_eq!(Nationality::from(10), Nationality::British);
+    assert_eq!(Nationality::from(87).short_code(), Some("VIE"));
+    assert_eq!(Nationality::NewZealander.to_string(), "New Zealander");
+    assert_eq!(Nationality::from(0), Nationality::Unknown(0));
+}
+
+#[test]
+fn test_round_trip_ids() {
+    for value in 0..=u8::MAX {
+        assert_eq!(u8::from(Team::from(value)), value);
+        assert_eq!(u8::from(Driver::from(value)), value);
+        assert_eq!(u8::from(Track::from(value)), value);
+        assert_eq!(u8::from(Nationality::from(value)), value);
+    }
+}
+
+#[test]
+fn test_packet_accessors() {
+    match parse_sample("participants.pkt").data {
+        PacketType::Participants(data) => {
+            let participant = &data.participants[0];
+
+            assert_eq!(participant.driver(), Driver::LewisHamilton);
+            assert_eq!(participant.team(), Team::Mercedes);
+            assert_eq!(participant.nationality(), Nationality::British);
+        }
+        _ => panic!("wrong packet type"),
+    }
+
+    match parse_sample("session.pkt").data {
+        PacketType::Session(data) => assert_eq!(data.track(), Track::Melbourne),
+        _ => panic!("wrong packet type"),
+    }
+}
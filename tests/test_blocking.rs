@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+use std::time::Duration;
+
+use f1_2021_telemetry::error::F1Error;
+use f1_2021_telemetry::packet;
+use f1_2021_telemetry::F1_2021;
+
+mod common;
+
+use common::{free_local_address, read_sample};
+
+#[test]
+fn test_blocking_telemetry() {
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+    sender.send_to(&[0, 1, 2], addr).unwrap();
+    sender
+        .send_to(&read_sample("car_telemetry.pkt"), addr)
+        .unwrap();
+
+    assert!(matches!(
+        telemetry.next(),
+        Some(Err(F1Error::IncompleteData))
+    ));
+
+    let packet = telemetry.next().unwrap().unwrap();
+    assert!(matches!(packet.data, packet::PacketType::CarTelemetry(_)));
+}
+
+#[test]
+fn test_blocking_telemetry_timeout() {
+    let mut telemetry = F1_2021::blocking_telemetry(free_local_address()).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(50)))
+        .unwrap();
+
+    match telemetry.next() {
+        Some(Err(F1Error::IoError(err))) => {
+            assert!(matches!(
+                err.kind(),
+                ErrorKind::WouldBlock | ErrorKind::TimedOut
+            ))
+        }
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
@@ -0,0 +1,56 @@
+use f1_2021_telemetry::packet::event::{ButtonFlags, ButtonSet};
+
+#[test]
+fn test_button_set() {
+    let mut set = ButtonSet::from(ButtonFlags::A);
+    set.insert(ButtonFlags::RT);
+
+    assert_eq!(set.bits(), 0x00001001);
+    assert!(set.contains(ButtonFlags::A));
+    assert!(set.contains(ButtonFlags::RT));
+    assert!(!set.contains(ButtonFlags::B));
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![ButtonFlags::A, ButtonFlags::RT]
+    );
+
+    set.remove(ButtonFlags::A);
+    assert_eq!(set, ButtonSet::from(ButtonFlags::RT));
+
+    // Unknown bits are kept
+    let raw = ButtonSet::from_bits(0x80000004);
+    assert_eq!(raw.bits(), 0x80000004);
+    assert_eq!(raw.iter().collect::<Vec<_>>(), vec![ButtonFlags::B]);
+}
+
+#[test]
+fn test_button_set_operations() {
+    let a: ButtonSet = [ButtonFlags::A, ButtonFlags::B].into_iter().collect();
+    let b: ButtonSet = [ButtonFlags::B, ButtonFlags::X].into_iter().collect();
+
+    assert_eq!(
+        a.union(&b),
+        [ButtonFlags::A, ButtonFlags::B, ButtonFlags::X]
+            .into_iter()
+            .collect()
+    );
+    assert_eq!(a.intersection(&b), ButtonSet::from(ButtonFlags::B));
+    assert_eq!(a.difference(&b), ButtonSet::from(ButtonFlags::A));
+    assert!(a.difference(&a).is_empty());
+}
+
+#[test]
+fn test_button_set_edges() {
+    let previous: ButtonSet = [ButtonFlags::A, ButtonFlags::LB].into_iter().collect();
+    let current: ButtonSet = [ButtonFlags::A, ButtonFlags::RB].into_iter().collect();
+
+    assert_eq!(
+        current.pressed_since(&previous),
+        ButtonSet::from(ButtonFlags::RB)
+    );
+    assert_eq!(
+        current.released_since(&previous),
+        ButtonSet::from(ButtonFlags::LB)
+    );
+    assert!(current.pressed_since(&current).is_empty());
+}
@@ -0,0 +1,101 @@
+use bytes::BytesMut;
+use std::io::Cursor;
+
+use f1_2021_telemetry::packet;
+use f1_2021_telemetry::packet::parse_packet;
+
+mod common;
+
+use common::{parse_sample, read_sample};
+
+#[test]
+fn test_round_trip_fixed_size_packets() {
+    let samples = [
+        "motion.pkt",
+        "session.pkt",
+        "lap_data.pkt",
+        "participants.pkt",
+        "car_setups.pkt",
+        "car_telemetry.pkt",
+        "car_status.pkt",
+        "final_classification.pkt",
+        "lobby_info.pkt",
+        "car_damage.pkt",
+        "session_history.pkt",
+    ];
+
+    for sample in samples {
+        let packet = parse_sample(sample);
+
+        assert_eq!(
+            &packet.to_bytes()[..],
+            &read_sample(sample)[..],
+            "{}",
+            sample
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_event_packets() {
+    for sample in [
+        "event_ssta.pkt",
+        "event_fltp.pkt",
+        "event_butn.pkt",
+        "event_pena.pkt",
+    ] {
+        let raw = read_sample(sample);
+        let packet = parse_sample(sample);
+
+        let bytes = packet.to_bytes();
+
+        // Events are padded to the size of the largest event details
+        assert_eq!(
+            bytes.len(),
+            packet::header::HEADER_SIZE + packet::event::EVENT_PACKET_SIZE
+        );
+        assert_eq!(&bytes[..raw.len()], &raw[..], "{}", sample);
+        assert!(bytes[raw.len()..].iter().all(|&b| b == 0), "{}", sample);
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_encoder() {
+    use f1_2021_telemetry::F1_2021_Encoder;
+    use tokio_util::codec::Encoder;
+
+    let packet = parse_sample("car_telemetry.pkt");
+
+    let mut buf = BytesMut::new();
+    F1_2021_Encoder.encode(packet.clone(), &mut buf).unwrap();
+
+    assert_eq!(&buf[..], &packet.to_bytes()[..]);
+}
+
+#[test]
+fn test_encode_long_name() {
+    let mut packet = parse_sample("participants.pkt");
+
+    let name = "Ñ".repeat(30);
+
+    if let packet::PacketType::Participants(data) = &mut packet.data {
+        data.participants[0].name = name.clone();
+    }
+
+    let mut buf = BytesMut::from(&packet.to_bytes()[..]);
+
+    assert_eq!(buf.len(), 1257);
+
+    let mut cursor = Cursor::new(&mut buf);
+    let parsed = parse_packet(&mut cursor).unwrap();
+
+    match parsed.data {
+        packet::PacketType::Participants(data) => {
+            // 47 bytes are available before the null terminator
+            assert_eq!(data.participants[0].name, "Ñ".repeat(23));
+            assert_eq!(data.participants[1].name, "Lewis Hamilton");
+        }
+        _ => panic!("wrong packet type"),
+    }
+}
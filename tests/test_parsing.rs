@@ -22,7 +22,7 @@ fn test_parse_header() {
 
     let mut f = File::open("tests/packet_samples/header.pkt").expect("no file found");
     let mut buf = vec![0u8; packet::header::HEADER_SIZE];
-    f.read(&mut buf).expect("buffer overflow");
+    f.read_exact(&mut buf).expect("buffer overflow");
 
     let mut buf = BytesMut::from(&buf[..]);
 
@@ -53,7 +53,7 @@ fn test_parse_motion() {
     let motion_data = {
         let mut car_motion_data: [CarMotionData; NUMBER_OF_CARS] = [CarMotionData::default(); 22];
 
-        for i in 0..NUMBER_OF_CARS {
+        for car_motion in car_motion_data.iter_mut() {
             let world_positon = Coordinates3D {
                 x: 1.0,
                 y: 2.0,
@@ -78,7 +78,7 @@ fn test_parse_motion() {
             let pitch = 0.0f32;
             let roll = 0.0f32;
 
-            car_motion_data[i] = CarMotionData {
+            *car_motion = CarMotionData {
                 world_positon,
                 world_velocity,
                 world_forward_dir,
@@ -176,7 +176,7 @@ fn test_parse_motion() {
 }
 
 #[test]
-fn test_parse_event_SSTA() {
+fn test_parse_event_ssta() {
     let header = packet::header::Header {
         format: 2021,
         version: (1, 2),
@@ -244,6 +244,45 @@ fn test_parse_event_ftlp() {
     assert_eq!(packet::PacketType::Event(event_data), packet.data);
 }
 
+#[test]
+fn test_parse_event_pena() {
+    use packet::event::{InfringementType, PenaltyType};
+
+    let event_data = packet::event::EventData {
+        event_string_code: packet::event::EventCode::PenaltyIssued,
+        event_details: packet::event::EventDataDetails::Penalty {
+            penalty_type: PenaltyType::TimePenalty,
+            infringement_type: InfringementType::CornerCuttingGainedTime,
+            vehicle_idx: 3,
+            other_vehicle_idx: 255,
+            time: 5,
+            lap_num: 2,
+            places_gained: 0,
+        },
+    };
+
+    let mut f = File::open("tests/packet_samples/event_pena.pkt").expect("no file found");
+    let mut buf = Vec::new();
+    f.read_to_end(&mut buf).expect("can't read file");
+
+    let packet = packet::Packet::parse(&buf).unwrap();
+
+    assert_eq!(packet::PacketType::Event(event_data), packet.data);
+
+    assert_eq!(PenaltyType::TimePenalty.to_string(), "Time penalty");
+    assert_eq!(
+        InfringementType::CornerCuttingGainedTime.to_string(),
+        "Corner cutting gained time"
+    );
+    assert_eq!(
+        InfringementType::from(54),
+        InfringementType::AttributeAssigned
+    );
+    assert_eq!(InfringementType::from(52), InfringementType::Unknown(52));
+    assert_eq!(u8::from(PenaltyType::Unknown(200)), 200);
+    assert_eq!(PenaltyType::from(200).to_string(), "Unknown (200)");
+}
+
 #[test]
 fn test_parse_event_butn() {
     let header = packet::header::Header {
@@ -258,10 +297,12 @@ fn test_parse_event_butn() {
         secondary_player_car_index: 255,
     };
 
-    let mut buttons_set = std::collections::HashSet::new();
-
-    buttons_set.insert(packet::event::ButtonFlags::A);
-    buttons_set.insert(packet::event::ButtonFlags::RT);
+    let buttons_set: packet::event::ButtonSet = [
+        packet::event::ButtonFlags::A,
+        packet::event::ButtonFlags::RT,
+    ]
+    .into_iter()
+    .collect();
 
     let event_data = packet::event::EventData {
         event_string_code: packet::event::EventCode::ButtonStatus,
@@ -305,7 +346,7 @@ fn test_parse_telemetry() {
         let mut car_telemetry_data: [CarTelemetryData; NUMBER_OF_CARS] =
             [CarTelemetryData::default(); NUMBER_OF_CARS];
 
-        for i in 0..NUMBER_OF_CARS {
+        for car_telemetry in car_telemetry_data.iter_mut() {
             let speed = 123;
             let throttle = 1.0;
             let steer = 0.0;
@@ -342,13 +383,13 @@ fn test_parse_telemetry() {
                 front_right: 50.0,
             };
             let surface_type = WheelsData {
-                rear_left: 0,
-                rear_right: 0,
-                front_left: 0,
-                front_right: 0,
+                rear_left: SurfaceType::Tarmac,
+                rear_right: SurfaceType::Tarmac,
+                front_left: SurfaceType::Tarmac,
+                front_right: SurfaceType::Tarmac,
             };
 
-            car_telemetry_data[i] = CarTelemetryData {
+            *car_telemetry = CarTelemetryData {
                 speed,
                 throttle,
                 steer,
@@ -396,3 +437,673 @@ fn test_parse_telemetry() {
         packet.data
     );
 }
+
+#[test]
+fn test_parse_session() {
+    use packet::session::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::Session,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let session_data = {
+        let marshal_zones = [MarshalZone {
+            zone_start: 0.5,
+            zone_flag: 1,
+        }; MAX_MARSHAL_ZONES];
+
+        let weather_forecast_samples = [WeatherForecastSample {
+            session_type: 10,
+            time_offset: 5,
+            weather: 1,
+            track_temperature: 31,
+            track_temperature_change: 0,
+            air_temperature: 24,
+            air_temperature_change: 2,
+            rain_percentage: 10,
+        }; MAX_WEATHER_FORECAST_SAMPLES];
+
+        SessionData {
+            weather: 1,
+            track_temperature: 30,
+            air_temperature: 25,
+            total_laps: 5,
+            track_length: 5300,
+            session_type: 10,
+            track_id: 0,
+            formula: 0,
+            session_time_left: 3600,
+            session_duration: 7200,
+            pit_speed_limit: 80,
+            game_paused: false,
+            is_spectating: false,
+            spectator_car_index: 255,
+            sli_pro_native_support: false,
+            num_marshal_zones: 3,
+            marshal_zones,
+            safety_car_status: 0,
+            network_game: false,
+            num_weather_forecast_samples: 2,
+            weather_forecast_samples,
+            forecast_accuracy: 0,
+            ai_difficulty: 90,
+            season_link_identifier: 1,
+            weekend_link_identifier: 2,
+            session_link_identifier: 3,
+            pit_stop_window_ideal_lap: 10,
+            pit_stop_window_latest_lap: 15,
+            pit_stop_rejoin_position: 5,
+            steering_assist: false,
+            braking_assist: 1,
+            gearbox_assist: 3,
+            pit_assist: true,
+            pit_release_assist: false,
+            ers_assist: false,
+            drs_assist: true,
+            dynamic_racing_line: 2,
+            dynamic_racing_line_type: 1,
+        }
+    };
+
+    let mut f = File::open("tests/packet_samples/session.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(packet::PacketType::Session(session_data), packet.data);
+}
+
+#[test]
+fn test_parse_lap_data() {
+    use f1_2021_telemetry::utils::*;
+    use packet::lap_data::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::LapData,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let lap_data = {
+        let car_lap_data = [CarLapData {
+            last_lap_time: 90500,
+            current_lap_time: 30250,
+            sector1_time: 29000,
+            sector2_time: 31000,
+            lap_distance: 1500.5,
+            total_distance: 7000.5,
+            safety_car_delta: 0.0,
+            car_position: 3,
+            current_lap_num: 2,
+            pit_status: 0,
+            num_pit_stops: 1,
+            sector: 1,
+            current_lap_invalid: false,
+            penalties: 5,
+            warnings: 1,
+            num_unserved_drive_through_pens: 0,
+            num_unserved_stop_go_pens: 0,
+            grid_position: 4,
+            driver_status: 4,
+            result_status: 2,
+            pit_lane_timer_active: false,
+            pit_lane_time_in_lane: 0,
+            pit_stop_timer: 0,
+            pit_stop_should_serve_pen: false,
+        }; NUMBER_OF_CARS];
+
+        LapData { car_lap_data }
+    };
+
+    let mut f = File::open("tests/packet_samples/lap_data.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(packet::PacketType::LapData(lap_data), packet.data);
+}
+
+#[test]
+fn test_parse_participants() {
+    use packet::participants::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::Participants,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let participants_data = {
+        let participants: [ParticipantData; 22] = std::array::from_fn(|_| ParticipantData {
+            ai_controlled: true,
+            driver_id: 7,
+            network_id: 0,
+            team_id: 0,
+            my_team: false,
+            race_number: 44,
+            nationality: 10,
+            name: "Lewis Hamilton".to_string(),
+            public_telemetry: true,
+        });
+
+        ParticipantsData {
+            num_active_cars: 20,
+            participants,
+        }
+    };
+
+    let mut f = File::open("tests/packet_samples/participants.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(
+        packet::PacketType::Participants(participants_data),
+        packet.data
+    );
+}
+
+#[test]
+fn test_parse_participants_invalid_name() {
+    let mut f = File::open("tests/packet_samples/participants.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    // First byte of the first participant name
+    buf[packet::header::HEADER_SIZE + 1 + 7] = 0xff;
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let result = parse_packet(&mut cursor);
+
+    assert!(matches!(
+        result,
+        Err(f1_2021_telemetry::error::F1Error::UTF8Error(_))
+    ));
+}
+
+#[test]
+fn test_parse_car_setups() {
+    use f1_2021_telemetry::utils::*;
+    use packet::car_setups::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::CarSetups,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let car_setups_data = {
+        let car_setups = [CarSetupData {
+            front_wing: 5,
+            rear_wing: 6,
+            on_throttle: 70,
+            off_throttle: 55,
+            front_camber: -3.0,
+            rear_camber: -1.5,
+            front_toe: 0.0625,
+            rear_toe: 0.25,
+            front_suspension: 5,
+            rear_suspension: 4,
+            front_anti_roll_bar: 6,
+            rear_anti_roll_bar: 3,
+            front_suspension_height: 3,
+            rear_suspension_height: 6,
+            brake_pressure: 100,
+            brake_bias: 56,
+            tyres_pressure: WheelsData {
+                rear_left: 22.5,
+                rear_right: 22.5,
+                front_left: 23.5,
+                front_right: 23.5,
+            },
+            ballast: 0,
+            fuel_load: 5.5,
+        }; NUMBER_OF_CARS];
+
+        CarSetupsData { car_setups }
+    };
+
+    let mut f = File::open("tests/packet_samples/car_setups.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(packet::PacketType::CarSetups(car_setups_data), packet.data);
+}
+
+#[test]
+fn test_parse_car_status() {
+    use f1_2021_telemetry::utils::*;
+    use packet::car_status::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::CarStatus,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let car_status = {
+        let car_status_data = [CarStatusData {
+            traction_control: 2,
+            anti_lock_brakes: true,
+            fuel_mix: 1,
+            front_brake_bias: 56,
+            pit_limiter_status: false,
+            fuel_in_tank: 50.5,
+            fuel_capacity: 110.0,
+            fuel_remaining_laps: 12.25,
+            max_rpm: 13000,
+            idle_rpm: 4000,
+            max_gears: 8,
+            drs_allowed: true,
+            drs_activation_distance: 0,
+            actual_tyre_compound: 18,
+            visual_tyre_compound: 16,
+            tyres_age_laps: 3,
+            vehicle_fia_flags: 1,
+            ers_store_energy: 2000000.0,
+            ers_deploy_mode: 1,
+            ers_harvested_this_lap_mguk: 10000.0,
+            ers_harvested_this_lap_mguh: 20000.0,
+            ers_deployed_this_lap: 30000.0,
+            network_paused: false,
+        }; NUMBER_OF_CARS];
+
+        StatusData { car_status_data }
+    };
+
+    let mut f = File::open("tests/packet_samples/car_status.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(packet::PacketType::CarStatus(car_status), packet.data);
+}
+
+#[test]
+fn test_parse_final_classification() {
+    use f1_2021_telemetry::utils::*;
+    use packet::final_classification::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::FinalClassification,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let final_classification_data = {
+        let classification_data = [CarFinalClassificationData {
+            position: 1,
+            num_laps: 58,
+            grid_position: 2,
+            points: 25,
+            num_pit_stops: 2,
+            result_status: 3,
+            best_lap_time: 85123,
+            total_race_time: 5400.5,
+            penalties_time: 5,
+            num_penalties: 1,
+            num_tyre_stints: 3,
+            tyre_stints_actual: [18, 17, 18, 0, 0, 0, 0, 0],
+            tyre_stints_visual: [16, 17, 16, 0, 0, 0, 0, 0],
+        }; NUMBER_OF_CARS];
+
+        FinalClassificationData {
+            num_cars: 20,
+            classification_data,
+        }
+    };
+
+    let mut f = File::open("tests/packet_samples/final_classification.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(
+        packet::PacketType::FinalClassification(final_classification_data),
+        packet.data
+    );
+}
+
+#[test]
+fn test_parse_lobby_info() {
+    use packet::lobby_info::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::LobbyInfo,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let lobby_data = {
+        let lobby_players: [LobbyInfoData; 22] = std::array::from_fn(|_| LobbyInfoData {
+            ai_controlled: false,
+            team_id: 2,
+            nationality: 82,
+            name: "Sérgio Pérez".to_string(),
+            car_number: 11,
+            ready_status: 1,
+        });
+
+        LobbyData {
+            num_players: 2,
+            lobby_players,
+        }
+    };
+
+    let mut f = File::open("tests/packet_samples/lobby_info.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(packet::PacketType::LobbyInfo(lobby_data), packet.data);
+}
+
+#[test]
+fn test_parse_car_damage() {
+    use f1_2021_telemetry::utils::*;
+    use packet::car_damage::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::CarDamage,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let damage_data = {
+        let car_damage_data = [CarDamageData {
+            tyres_wear: WheelsData {
+                rear_left: 10.5,
+                rear_right: 11.5,
+                front_left: 8.25,
+                front_right: 8.75,
+            },
+            tyres_damage: WheelsData {
+                rear_left: 1,
+                rear_right: 1,
+                front_left: 0,
+                front_right: 0,
+            },
+            brakes_damage: WheelsData {
+                rear_left: 2,
+                rear_right: 2,
+                front_left: 3,
+                front_right: 3,
+            },
+            front_left_wing_damage: 15,
+            front_right_wing_damage: 0,
+            rear_wing_damage: 5,
+            floor_damage: 0,
+            diffuser_damage: 0,
+            sidepod_damage: 0,
+            drs_fault: true,
+            gear_box_damage: 4,
+            engine_damage: 6,
+            engine_mguh_wear: 12,
+            engine_es_wear: 13,
+            engine_ce_wear: 14,
+            engine_ice_wear: 15,
+            engine_mguk_wear: 16,
+            engine_tc_wear: 17,
+        }; NUMBER_OF_CARS];
+
+        DamageData { car_damage_data }
+    };
+
+    let mut f = File::open("tests/packet_samples/car_damage.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(packet::PacketType::CarDamage(damage_data), packet.data);
+}
+
+#[test]
+fn test_parse_session_history() {
+    use packet::session_history::*;
+
+    let header = packet::header::Header {
+        format: 2021,
+        version: (1, 2),
+        packet_version: 1,
+        packet_id: packet::header::PacketId::SessionHistory,
+        session_uid: 1,
+        session_time: 12.35,
+        frame_identifier: 123,
+        player_car_index: 1,
+        secondary_player_car_index: 255,
+    };
+
+    let session_history_data = SessionHistoryData {
+        car_idx: 1,
+        num_laps: 2,
+        num_tyre_stints: 2,
+        best_lap_time_lap_num: 1,
+        best_sector1_lap_num: 2,
+        best_sector2_lap_num: 1,
+        best_sector3_lap_num: 1,
+        lap_history_data: vec![
+            LapHistoryData {
+                lap_time: 90000,
+                sector1_time: 30000,
+                sector2_time: 30000,
+                sector3_time: 30000,
+                lap_valid: true,
+                sector1_valid: true,
+                sector2_valid: true,
+                sector3_valid: true,
+            },
+            LapHistoryData {
+                lap_time: 91000,
+                sector1_time: 31000,
+                sector2_time: 29000,
+                sector3_time: 31000,
+                lap_valid: true,
+                sector1_valid: true,
+                sector2_valid: false,
+                sector3_valid: true,
+            },
+        ],
+        tyre_stints_history_data: vec![
+            TyreStintHistoryData {
+                end_lap: 1,
+                tyre_actual_compound: 18,
+                tyre_visual_compound: 16,
+            },
+            TyreStintHistoryData {
+                end_lap: 255,
+                tyre_actual_compound: 17,
+                tyre_visual_compound: 17,
+            },
+        ],
+    };
+
+    let mut f = File::open("tests/packet_samples/session_history.pkt").expect("no file found");
+    let mut buf = vec![0u8; 2048];
+    let size = f.read(&mut buf).expect("buffer overflow");
+
+    let mut buf = BytesMut::from(&buf[..size]);
+
+    let mut cursor = Cursor::new(&mut buf);
+
+    let packet = parse_packet(&mut cursor).unwrap();
+
+    assert_eq!(header, packet.header);
+    assert_eq!(
+        packet::PacketType::SessionHistory(session_history_data),
+        packet.data
+    );
+}
+
+#[test]
+fn test_parse_header_from_slice() {
+    let mut f = File::open("tests/packet_samples/header.pkt").expect("no file found");
+    let mut raw = Vec::new();
+    f.read_to_end(&mut raw).expect("can't read file");
+
+    let mut buf = BytesMut::from(&raw[..]);
+    let mut cursor = Cursor::new(&mut buf);
+
+    let mut slice = &raw[..];
+    let header = packet::header::parse_header(&mut slice).unwrap();
+
+    assert_eq!(header, packet::header::parse_header(&mut cursor).unwrap());
+    assert!(slice.is_empty());
+
+    assert!(matches!(
+        packet::header::parse_header(&mut &raw[..packet::header::HEADER_SIZE - 1]),
+        Err(f1_2021_telemetry::error::F1Error::IncompleteData)
+    ));
+}
+
+#[test]
+fn test_parse_from_slice() {
+    let mut f = File::open("tests/packet_samples/car_telemetry.pkt").expect("no file found");
+    let mut raw = Vec::new();
+    f.read_to_end(&mut raw).expect("can't read file");
+
+    let mut buf = BytesMut::from(&raw[..]);
+    let mut cursor = Cursor::new(&mut buf);
+
+    assert_eq!(
+        packet::Packet::parse(&raw).unwrap(),
+        parse_packet(&mut cursor).unwrap()
+    );
+
+    assert!(matches!(
+        packet::Packet::parse(&raw[..raw.len() - 1]),
+        Err(f1_2021_telemetry::error::F1Error::IncompleteData)
+    ));
+}
+
+#[test]
+fn test_surface_type() {
+    use f1_2021_telemetry::utils::WheelsData;
+    use packet::car_telemetry::SurfaceType;
+
+    let surface = WheelsData {
+        rear_left: 7u8,
+        rear_right: 4,
+        front_left: 1,
+        front_right: 42,
+    }
+    .map(SurfaceType::from);
+
+    assert_eq!(surface.rear_left, SurfaceType::Grass);
+    assert_eq!(surface.rear_left.to_string(), "Grass");
+    assert_eq!(surface.front_right, SurfaceType::Unknown(42));
+    assert_eq!(u8::from(surface.front_right), 42);
+
+    assert_eq!(surface.wheels_off_track(), 2);
+    assert_eq!(surface.wheels_on_kerb(), 1);
+    assert!(!surface.is_off_track());
+
+    let gravel = WheelsData {
+        rear_left: SurfaceType::Gravel,
+        rear_right: SurfaceType::Gravel,
+        front_left: SurfaceType::Grass,
+        front_right: SurfaceType::Sand,
+    };
+    assert!(gravel.is_off_track());
+}
@@ -0,0 +1,415 @@
+#![cfg(feature = "std")]
+
+use std::net::SocketAddr;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use f1_2021_telemetry::error::F1Error;
+use f1_2021_telemetry::packet::{self, Packet};
+use f1_2021_telemetry::pcap::{PcapReader, PcapWriter};
+use f1_2021_telemetry::ReceivedPacket;
+
+mod common;
+
+use common::read_sample;
+
+/// Raw IP packet as synthesized by `PcapWriter`
+fn ip_packet(source: &str, destination: &str, payload: &[u8]) -> Vec<u8> {
+    let mut writer = PcapWriter::new(Vec::new()).unwrap();
+
+    writer
+        .write_datagram(
+            UNIX_EPOCH,
+            source.parse().unwrap(),
+            destination.parse().unwrap(),
+            payload,
+        )
+        .unwrap();
+
+    writer.into_inner()[24 + 16..].to_vec()
+}
+
+fn pcap_with_link_type(link_type: u32, frames: &[Vec<u8>]) -> Vec<u8> {
+    let mut capture = Vec::new();
+
+    capture.extend_from_slice(&0xa1b2c3d4u32.to_be_bytes());
+    capture.extend_from_slice(&2u16.to_be_bytes());
+    capture.extend_from_slice(&4u16.to_be_bytes());
+    capture.extend_from_slice(&[0; 8]);
+    capture.extend_from_slice(&65535u32.to_be_bytes());
+    capture.extend_from_slice(&link_type.to_be_bytes());
+
+    for (i, frame) in frames.iter().enumerate() {
+        capture.extend_from_slice(&(i as u32 + 1).to_be_bytes());
+        capture.extend_from_slice(&0u32.to_be_bytes());
+        capture.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+        capture.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+        capture.extend_from_slice(frame);
+    }
+
+    capture
+}
+
+fn pcapng_block(block_type: u32, body: &[u8]) -> Vec<u8> {
+    let padded_length = (body.len() + 3) & !3;
+    let block_length = (padded_length + 12) as u32;
+
+    let mut block = Vec::new();
+    block.extend_from_slice(&block_type.to_le_bytes());
+    block.extend_from_slice(&block_length.to_le_bytes());
+    block.extend_from_slice(body);
+    block.resize(8 + padded_length, 0);
+    block.extend_from_slice(&block_length.to_le_bytes());
+
+    block
+}
+
+fn section_header_block() -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&0x1a2b3c4du32.to_le_bytes());
+    body.extend_from_slice(&1u16.to_le_bytes());
+    body.extend_from_slice(&0u16.to_le_bytes());
+    body.extend_from_slice(&(-1i64).to_le_bytes());
+
+    pcapng_block(0x0a0d0d0a, &body)
+}
+
+/// Interface with an `if_tsresol` option, timestamps are in 10^-`resolution` seconds
+fn interface_block(link_type: u16, resolution: u8) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&link_type.to_le_bytes());
+    body.extend_from_slice(&0u16.to_le_bytes());
+    body.extend_from_slice(&0u32.to_le_bytes());
+    body.extend_from_slice(&9u16.to_le_bytes());
+    body.extend_from_slice(&1u16.to_le_bytes());
+    body.extend_from_slice(&[resolution, 0, 0, 0]);
+    body.extend_from_slice(&[0; 4]);
+
+    pcapng_block(1, &body)
+}
+
+fn enhanced_packet_block(timestamp: u64, frame: &[u8]) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&0u32.to_le_bytes());
+    body.extend_from_slice(&((timestamp >> 32) as u32).to_le_bytes());
+    body.extend_from_slice(&(timestamp as u32).to_le_bytes());
+    body.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+    body.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+    body.extend_from_slice(frame);
+
+    pcapng_block(6, &body)
+}
+
+fn ethernet_frame(ethertype: u16, ip_packet: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0u8; 12];
+    frame.extend_from_slice(&ethertype.to_be_bytes());
+    frame.extend_from_slice(ip_packet);
+
+    frame
+}
+
+#[test]
+fn test_writer_round_trip() {
+    let motion = read_sample("motion.pkt");
+    let event = read_sample("event_butn.pkt");
+    let source: SocketAddr = "192.168.1.20:50000".parse().unwrap();
+    let destination: SocketAddr = "192.168.1.10:20777".parse().unwrap();
+    let timestamp = UNIX_EPOCH + Duration::from_micros(1_600_000_000_123_456);
+
+    let mut writer = PcapWriter::new(Vec::new()).unwrap();
+    writer
+        .write_datagram(timestamp, source, destination, &motion)
+        .unwrap();
+    writer
+        .write_datagram(timestamp, source, destination, &event)
+        .unwrap();
+
+    let capture = writer.into_inner();
+    let mut reader = PcapReader::new(&capture[..]).unwrap();
+
+    let datagram = reader.next_datagram().unwrap().unwrap();
+    assert_eq!(datagram.timestamp, timestamp);
+    assert_eq!(datagram.source, source);
+    assert_eq!(datagram.destination, destination);
+    assert_eq!(datagram.payload, motion);
+
+    let (_, packet) = reader.next().unwrap().unwrap();
+    assert_eq!(packet, packet::parse_packet(&mut &event[..]).unwrap());
+
+    assert!(reader.next_datagram().unwrap().is_none());
+}
+
+#[test]
+fn test_writer_checksums() {
+    let frame = ip_packet(
+        "10.0.0.1:50000",
+        "10.0.0.2:20777",
+        &read_sample("event_butn.pkt"),
+    );
+
+    // One's complement sums over a valid header and datagram are all ones
+    let sum = |words: &[u8]| {
+        let mut sum: u32 = words
+            .chunks(2)
+            .map(|w| u16::from_be_bytes([w[0], *w.get(1).unwrap_or(&0)]) as u32)
+            .sum();
+        while sum > 0xffff {
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        sum
+    };
+
+    assert_eq!(sum(&frame[..20]), 0xffff);
+
+    let udp = &frame[20..];
+    let mut pseudo_header = frame[12..20].to_vec();
+    pseudo_header.extend_from_slice(&[0, 17]);
+    pseudo_header.extend_from_slice(&(udp.len() as u16).to_be_bytes());
+    pseudo_header.extend_from_slice(udp);
+
+    assert_eq!(sum(&pseudo_header), 0xffff);
+}
+
+#[test]
+fn test_port_filter() {
+    let motion = read_sample("motion.pkt");
+    let frames = vec![
+        ip_packet("10.0.0.1:50000", "10.0.0.2:20777", &motion),
+        ip_packet("10.0.0.1:50000", "10.0.0.2:53", b"not telemetry"),
+        ip_packet("10.0.0.1:50000", "10.0.0.2:2021", &motion),
+        ip_packet("10.0.0.1:50000", "10.0.0.2:30500", &motion),
+    ];
+    let capture = pcap_with_link_type(101, &frames);
+
+    let ports = |reader: PcapReader<&[u8]>| -> Vec<u16> {
+        reader
+            .datagrams()
+            .map(|datagram| datagram.unwrap().destination.port())
+            .collect()
+    };
+
+    assert_eq!(
+        ports(PcapReader::new(&capture[..]).unwrap()),
+        vec![20777, 2021]
+    );
+    assert_eq!(
+        ports(PcapReader::new(&capture[..]).unwrap().with_ports(&[30500])),
+        vec![30500]
+    );
+    assert_eq!(
+        ports(PcapReader::new(&capture[..]).unwrap().with_any_port()),
+        vec![20777, 53, 2021, 30500]
+    );
+}
+
+#[test]
+fn test_link_types() {
+    let motion = read_sample("motion.pkt");
+    let v4 = ip_packet("10.0.0.1:50000", "10.0.0.2:20777", &motion);
+    let v6 = ip_packet("[fe80::1]:50000", "[fe80::2]:20777", &motion);
+
+    let mut vlan = vec![0u8; 12];
+    vlan.extend_from_slice(&[0x81, 0x00, 0x00, 0x05, 0x86, 0xdd]);
+    vlan.extend_from_slice(&v6);
+
+    let mut sll = vec![0u8; 14];
+    sll.extend_from_slice(&0x0800u16.to_be_bytes());
+    sll.extend_from_slice(&v4);
+
+    let mut sll2 = 0x86ddu16.to_be_bytes().to_vec();
+    sll2.resize(20, 0);
+    sll2.extend_from_slice(&v6);
+
+    let mut null = 2u32.to_le_bytes().to_vec();
+    null.extend_from_slice(&v4);
+
+    let captures = [
+        pcap_with_link_type(
+            1,
+            &[
+                ethernet_frame(0x0800, &v4),
+                ethernet_frame(0x86dd, &v6),
+                vlan,
+            ],
+        ),
+        pcap_with_link_type(113, &[sll]),
+        pcap_with_link_type(276, &[sll2]),
+        pcap_with_link_type(0, &[null]),
+        pcap_with_link_type(229, std::slice::from_ref(&v6)),
+    ];
+
+    let expected_counts = [3, 1, 1, 1, 1];
+
+    for (capture, expected_count) in captures.iter().zip(expected_counts) {
+        let datagrams: Vec<_> = PcapReader::new(&capture[..])
+            .unwrap()
+            .datagrams()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(datagrams.len(), expected_count);
+
+        for datagram in datagrams {
+            assert_eq!(datagram.destination.port(), 20777);
+            assert_eq!(datagram.payload, motion);
+        }
+    }
+
+    let v6_datagram = PcapReader::new(&pcap_with_link_type(229, &[v6])[..])
+        .unwrap()
+        .next_datagram()
+        .unwrap()
+        .unwrap();
+    assert_eq!(v6_datagram.source, "[fe80::1]:50000".parse().unwrap());
+}
+
+#[test]
+fn test_pcapng() {
+    let motion = read_sample("motion.pkt");
+    let frame = ethernet_frame(
+        0x0800,
+        &ip_packet("10.0.0.1:50000", "10.0.0.2:20777", &motion),
+    );
+
+    let timestamp = 1_600_000_000_123_456_789u64;
+
+    let mut simple_packet = (frame.len() as u32).to_le_bytes().to_vec();
+    simple_packet.extend_from_slice(&frame);
+
+    let mut capture = section_header_block();
+    // Ethernet with nanosecond timestamps
+    capture.extend(interface_block(1, 9));
+    // Name resolution blocks are skipped
+    capture.extend(pcapng_block(4, &[0; 4]));
+    capture.extend(enhanced_packet_block(timestamp, &frame));
+    capture.extend(pcapng_block(3, &simple_packet));
+
+    let datagrams: Vec<_> = PcapReader::new(&capture[..])
+        .unwrap()
+        .datagrams()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(datagrams.len(), 2);
+    assert_eq!(
+        datagrams[0].timestamp,
+        UNIX_EPOCH + Duration::from_nanos(timestamp)
+    );
+    assert_eq!(datagrams[0].payload, motion);
+    assert_eq!(datagrams[1].timestamp, UNIX_EPOCH);
+    assert_eq!(
+        datagrams[1].packet().unwrap(),
+        Packet::parse(&motion).unwrap()
+    );
+}
+
+#[test]
+fn test_skipped_frames() {
+    let motion = read_sample("motion.pkt");
+    let whole = ip_packet("10.0.0.1:50000", "10.0.0.2:20777", &motion);
+
+    let mut fragment = whole.clone();
+    fragment[6] = 0x20;
+
+    let truncated = whole[..200].to_vec();
+
+    let capture = pcap_with_link_type(101, &[fragment, truncated]);
+
+    assert!(PcapReader::new(&capture[..])
+        .unwrap()
+        .next_datagram()
+        .unwrap()
+        .is_none());
+
+    // Only the ethertype tells this frame apart from the IPv4 one after it
+    let not_ip = ethernet_frame(0x0806, &whole);
+    let capture = pcap_with_link_type(1, &[not_ip, ethernet_frame(0x0800, &whole)]);
+
+    let datagrams: Vec<_> = PcapReader::new(&capture[..])
+        .unwrap()
+        .datagrams()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(datagrams.len(), 1);
+}
+
+#[test]
+fn test_write_received() {
+    let source: SocketAddr = "192.168.1.20:50000".parse().unwrap();
+    let destination: SocketAddr = "127.0.0.1:2021".parse().unwrap();
+
+    // Trailing bytes the decoder ignores and the encoder wouldn't write
+    let mut raw = read_sample("lap_data.pkt");
+    raw.extend_from_slice(&[0xAA; 3]);
+    let packet = Packet::parse(&raw).unwrap();
+
+    let received = ReceivedPacket {
+        packet: packet.clone(),
+        datagram: raw.clone(),
+        source,
+        received_at: Instant::now(),
+    };
+
+    let before = SystemTime::now() - Duration::from_secs(1);
+
+    let mut writer = PcapWriter::new(Vec::new()).unwrap();
+    writer.write_received(&received, destination).unwrap();
+
+    let capture = writer.into_inner();
+    let datagram = PcapReader::new(&capture[..])
+        .unwrap()
+        .next_datagram()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(datagram.source, source);
+    assert_eq!(datagram.destination, destination);
+    assert_eq!(datagram.payload, raw);
+    assert_eq!(datagram.packet().unwrap(), packet);
+    assert!(datagram.timestamp >= before && datagram.timestamp <= SystemTime::now());
+}
+
+#[test]
+fn test_timestamp_overflow() {
+    let frame = ethernet_frame(
+        0x0800,
+        &ip_packet(
+            "10.0.0.1:50000",
+            "10.0.0.2:20777",
+            &read_sample("motion.pkt"),
+        ),
+    );
+
+    // Whole seconds, the timestamp is far beyond what SystemTime can hold
+    let mut capture = section_header_block();
+    capture.extend(interface_block(1, 0));
+    capture.extend(enhanced_packet_block(u64::MAX, &frame));
+
+    let mut reader = PcapReader::new(&capture[..]).unwrap();
+
+    assert!(matches!(
+        reader.next_datagram(),
+        Err(F1Error::InvalidCapture)
+    ));
+}
+
+#[test]
+fn test_invalid_capture() {
+    assert!(matches!(
+        PcapReader::new(&b"F12021RC\x01\x00"[..]),
+        Err(F1Error::InvalidCapture)
+    ));
+    assert!(matches!(
+        PcapReader::new(&[][..]),
+        Err(F1Error::InvalidCapture)
+    ));
+
+    let motion = read_sample("motion.pkt");
+    let capture = pcap_with_link_type(101, &[ip_packet("10.0.0.1:1", "10.0.0.2:20777", &motion)]);
+
+    let mut reader = PcapReader::new(&capture[..capture.len() - 10]).unwrap();
+    assert!(matches!(
+        reader.next_datagram(),
+        Err(F1Error::IncompleteData)
+    ));
+}
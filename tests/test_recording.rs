@@ -0,0 +1,190 @@
+#![cfg(feature = "std")]
+
+use std::net::SocketAddr;
+use std::time::{Duration, UNIX_EPOCH};
+
+use f1_2021_telemetry::error::F1Error;
+use f1_2021_telemetry::packet::{self, Packet};
+use f1_2021_telemetry::recording::{Record, Recorder, RecordingReader, MAGIC};
+
+mod common;
+
+use common::read_sample;
+
+fn sample_recording() -> Vec<u8> {
+    let v4: SocketAddr = "192.168.1.20:50000".parse().unwrap();
+    let v6: SocketAddr = "[fe80::1]:50001".parse().unwrap();
+
+    let mut recorder = Recorder::new(Vec::new()).unwrap();
+
+    recorder
+        .record(
+            UNIX_EPOCH + Duration::from_micros(1_000_000),
+            v4,
+            &read_sample("motion.pkt"),
+        )
+        .unwrap();
+    recorder
+        .record(
+            UNIX_EPOCH + Duration::from_micros(1_016_667),
+            v6,
+            &read_sample("event_butn.pkt"),
+        )
+        .unwrap();
+
+    recorder.into_inner()
+}
+
+#[test]
+fn test_recording_round_trip() {
+    let recording = sample_recording();
+
+    assert_eq!(&recording[..8], &MAGIC);
+
+    let reader = RecordingReader::new(&recording[..]).unwrap();
+    assert_eq!(reader.version(), 1);
+
+    let packets: Vec<_> = reader.map(|result| result.unwrap()).collect();
+
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].0, UNIX_EPOCH + Duration::from_micros(1_000_000));
+    assert_eq!(
+        packets[0].1,
+        Packet::parse(&read_sample("motion.pkt")).unwrap()
+    );
+    assert_eq!(packets[1].0, UNIX_EPOCH + Duration::from_micros(1_016_667));
+    assert!(matches!(packets[1].1.data, packet::PacketType::Event(_)));
+}
+
+#[test]
+fn test_recording_raw_records() {
+    let recording = sample_recording();
+
+    let records: Vec<Record> = RecordingReader::new(&recording[..])
+        .unwrap()
+        .records()
+        .map(|result| result.unwrap())
+        .collect();
+
+    assert_eq!(records[0].source, "192.168.1.20:50000".parse().unwrap());
+    assert_eq!(records[0].datagram, read_sample("motion.pkt"));
+    assert_eq!(records[1].source, "[fe80::1]:50001".parse().unwrap());
+    assert_eq!(records[1].datagram, read_sample("event_butn.pkt"));
+}
+
+#[test]
+fn test_recording_errors() {
+    assert!(matches!(
+        RecordingReader::new(&b"not a recording"[..]),
+        Err(F1Error::InvalidRecording)
+    ));
+
+    // Recording cut in the middle of the last record
+    let recording = sample_recording();
+    let mut reader = RecordingReader::new(&recording[..recording.len() - 10]).unwrap();
+
+    assert!(reader.next().unwrap().is_ok());
+    assert!(matches!(reader.next(), Some(Err(F1Error::IncompleteData))));
+
+    // A corrupt length is rejected before anything is allocated
+    let mut recording = sample_recording()[..10].to_vec();
+    recording.extend_from_slice(&u32::MAX.to_le_bytes());
+    let mut reader = RecordingReader::new(&recording[..]).unwrap();
+
+    assert!(matches!(
+        reader.next_record(),
+        Err(F1Error::InvalidRecording)
+    ));
+}
+
+#[test]
+fn test_recording_timestamps() {
+    let source: SocketAddr = "127.0.0.1:20777".parse().unwrap();
+    let mut recorder = Recorder::new(Vec::new()).unwrap();
+
+    assert!(matches!(
+        recorder.record(UNIX_EPOCH - Duration::from_secs(1), source, &[]),
+        Err(F1Error::ConversionError)
+    ));
+
+    // Too many microseconds for the u64 field, if the platform can represent it at all
+    if let Some(timestamp) = UNIX_EPOCH.checked_add(Duration::from_secs(u64::MAX / 1_000_000 + 1)) {
+        assert!(matches!(
+            recorder.record(timestamp, source, &[]),
+            Err(F1Error::ConversionError)
+        ));
+    }
+
+    // A record with a timestamp of u64::MAX microseconds
+    let mut recording = sample_recording()[..10].to_vec();
+    recording.extend_from_slice(&(8u32 + 1 + 4 + 2).to_le_bytes());
+    recording.extend_from_slice(&u64::MAX.to_le_bytes());
+    recording.push(4);
+    recording.extend_from_slice(&[127, 0, 0, 1]);
+    recording.extend_from_slice(&20777u16.to_le_bytes());
+
+    let mut reader = RecordingReader::new(&recording[..]).unwrap();
+
+    // SystemTime can't hold it on every platform
+    match reader.next_record() {
+        Ok(Some(record)) => assert_eq!(
+            record.timestamp.duration_since(UNIX_EPOCH).unwrap(),
+            Duration::from_micros(u64::MAX)
+        ),
+        Err(F1Error::InvalidRecording) => {}
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_recording_append() {
+    let path = std::env::temp_dir().join(format!("f1_recording_{}.bin", std::process::id()));
+    let source: SocketAddr = "127.0.0.1:20777".parse().unwrap();
+    let datagram = read_sample("car_telemetry.pkt");
+
+    let _ = std::fs::remove_file(&path);
+
+    for _ in 0..2 {
+        let mut recorder = Recorder::append(&path).unwrap();
+        recorder.record(UNIX_EPOCH, source, &datagram).unwrap();
+        recorder.flush().unwrap();
+    }
+
+    let packets: Vec<_> = RecordingReader::open(&path)
+        .unwrap()
+        .map(|result| result.unwrap())
+        .collect();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(packets.len(), 2);
+}
+
+#[test]
+fn test_recording_append_after_partial_record() {
+    let path =
+        std::env::temp_dir().join(format!("f1_recording_partial_{}.bin", std::process::id()));
+    let source: SocketAddr = "127.0.0.1:20777".parse().unwrap();
+    let datagram = read_sample("car_telemetry.pkt");
+
+    // Crash in the middle of the second record
+    let recording = sample_recording();
+    std::fs::write(&path, &recording[..recording.len() - 10]).unwrap();
+
+    let mut recorder = Recorder::append(&path).unwrap();
+    recorder.record(UNIX_EPOCH, source, &datagram).unwrap();
+    recorder.flush().unwrap();
+    drop(recorder);
+
+    let records: Vec<Record> = RecordingReader::open(&path)
+        .unwrap()
+        .records()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].datagram, read_sample("motion.pkt"));
+    assert_eq!(records[1].datagram, datagram);
+}
@@ -0,0 +1,293 @@
+#![cfg(feature = "std")]
+
+use std::thread;
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+use f1_2021_telemetry::error::F1Error;
+use f1_2021_telemetry::packet::Packet;
+use f1_2021_telemetry::recording::{Record, Recorder};
+use f1_2021_telemetry::replay::{ReplaySpeed, Replayer, SeekTarget};
+use f1_2021_telemetry::F1_2021;
+
+mod common;
+
+use common::{free_local_address, read_sample};
+
+/// Telemetry packets 100ms apart, frame identifiers from 0 and session time in seconds
+fn sample_records(count: u32) -> Vec<Record> {
+    let mut packet = Packet::parse(&read_sample("car_telemetry.pkt")).unwrap();
+
+    (0..count)
+        .map(|i| {
+            packet.header.frame_identifier = i;
+            packet.header.session_time = i as f32 / 10.0;
+
+            Record {
+                timestamp: UNIX_EPOCH + Duration::from_millis(100 * i as u64),
+                source: "127.0.0.1:20777".parse().unwrap(),
+                datagram: packet.to_bytes().to_vec(),
+            }
+        })
+        .collect()
+}
+
+fn receive_frames(telemetry: &mut f1_2021_telemetry::blocking::BlockingTelemetry) -> Vec<u32> {
+    let mut frames = Vec::new();
+
+    loop {
+        match telemetry.next().unwrap() {
+            Ok(packet) => frames.push(packet.header.frame_identifier),
+            Err(F1Error::IoError(_)) => return frames,
+            Err(err) => panic!("unexpected error {:?}", err),
+        }
+    }
+}
+
+#[test]
+fn test_replay_as_fast_as_possible() {
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+
+    let mut replayer = Replayer::from_records(sample_records(5), addr).unwrap();
+    replayer.set_speed(ReplaySpeed::AsFastAsPossible).unwrap();
+
+    replayer.run().unwrap();
+
+    assert_eq!(receive_frames(&mut telemetry), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_replay_timing() {
+    let addr = free_local_address();
+    let _telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+
+    // 400ms of recording
+    let mut replayer = Replayer::from_records(sample_records(5), addr).unwrap();
+
+    replayer.set_speed(ReplaySpeed::Scaled(2.0)).unwrap();
+    let started = Instant::now();
+    replayer.run().unwrap();
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_millis(200), "{:?}", elapsed);
+
+    replayer.seek(SeekTarget::FrameIdentifier(2));
+    replayer.set_speed(ReplaySpeed::RealTime).unwrap();
+    let started = Instant::now();
+    replayer.run().unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(200));
+}
+
+#[test]
+fn test_replay_speed() {
+    let offset = Duration::from_millis(400);
+
+    assert_eq!(ReplaySpeed::RealTime.scale(offset), offset);
+    assert_eq!(
+        ReplaySpeed::Scaled(2.0).scale(offset),
+        Duration::from_millis(200)
+    );
+    assert_eq!(
+        ReplaySpeed::Scaled(0.5).scale(offset),
+        Duration::from_millis(800)
+    );
+    assert_eq!(ReplaySpeed::Scaled(1e-300).scale(offset), Duration::MAX);
+    assert_eq!(ReplaySpeed::AsFastAsPossible.scale(offset), Duration::ZERO);
+
+    let mut replayer = Replayer::from_records(sample_records(1), free_local_address()).unwrap();
+
+    for factor in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            replayer.set_speed(ReplaySpeed::Scaled(factor)),
+            Err(F1Error::ConversionError)
+        ));
+    }
+}
+
+#[test]
+fn test_replay_seek() {
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+
+    let mut replayer = Replayer::from_records(sample_records(5), addr).unwrap();
+    replayer.set_speed(ReplaySpeed::AsFastAsPossible).unwrap();
+
+    assert!(replayer.seek(SeekTarget::SessionTime(0.25)));
+    assert_eq!(replayer.position(), 3);
+    replayer.run().unwrap();
+    assert_eq!(receive_frames(&mut telemetry), vec![3, 4]);
+
+    assert!(replayer.seek(SeekTarget::FrameIdentifier(1)));
+    replayer.run().unwrap();
+    assert_eq!(receive_frames(&mut telemetry), vec![1, 2, 3, 4]);
+
+    assert!(!replayer.seek(SeekTarget::FrameIdentifier(10)));
+    assert_eq!(replayer.position(), replayer.len());
+}
+
+#[test]
+fn test_replay_pause_resume() {
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+
+    let mut replayer = Replayer::from_records(sample_records(3), addr).unwrap();
+    replayer.set_speed(ReplaySpeed::AsFastAsPossible).unwrap();
+
+    let handle = replayer.handle();
+    handle.pause();
+
+    let replay = thread::spawn(move || replayer.run());
+
+    // Nothing is sent while paused
+    assert!(receive_frames(&mut telemetry).is_empty());
+
+    handle.resume();
+    replay.join().unwrap().unwrap();
+
+    assert_eq!(receive_frames(&mut telemetry), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_replay_handle_seek() {
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .unwrap();
+
+    // Would take 10 seconds in real time
+    let mut replayer = Replayer::from_records(sample_records(101), addr).unwrap();
+
+    let handle = replayer.handle();
+    handle.pause();
+
+    let replay = thread::spawn(move || replayer.run());
+
+    // Applied by the replay thread before anything is sent
+    handle.seek(SeekTarget::FrameIdentifier(98));
+    handle.resume();
+
+    replay.join().unwrap().unwrap();
+
+    assert_eq!(receive_frames(&mut telemetry), vec![98, 99, 100]);
+}
+
+#[test]
+fn test_replay_from_file() {
+    let path = std::env::temp_dir().join(format!("f1_replay_{}.bin", std::process::id()));
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+
+    let mut recorder = Recorder::create(&path).unwrap();
+    for record in sample_records(5) {
+        recorder
+            .record(record.timestamp, record.source, &record.datagram)
+            .unwrap();
+    }
+    recorder.flush().unwrap();
+    drop(recorder);
+
+    let mut replayer = Replayer::open(&path, addr).unwrap();
+    replayer.set_speed(ReplaySpeed::AsFastAsPossible).unwrap();
+    assert_eq!(replayer.len(), 5);
+
+    assert!(replayer.seek(SeekTarget::FrameIdentifier(3)));
+    replayer.run().unwrap();
+    assert_eq!(receive_frames(&mut telemetry), vec![3, 4]);
+
+    // Seeking back reads from an earlier offset again
+    assert!(replayer.seek(SeekTarget::FrameIdentifier(0)));
+    replayer.run().unwrap();
+    assert_eq!(receive_frames(&mut telemetry), vec![0, 1, 2, 3, 4]);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_replay_truncated_recording() {
+    let path = std::env::temp_dir().join(format!("f1_replay_cut_{}.bin", std::process::id()));
+    let addr = free_local_address();
+    let mut telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+    telemetry
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+
+    let mut recorder = Recorder::new(Vec::new()).unwrap();
+    for record in sample_records(3) {
+        recorder
+            .record(record.timestamp, record.source, &record.datagram)
+            .unwrap();
+    }
+    let recording = recorder.into_inner();
+
+    // Crash in the middle of the last record
+    std::fs::write(&path, &recording[..recording.len() - 10]).unwrap();
+
+    let mut replayer = Replayer::open(&path, addr).unwrap();
+    replayer.set_speed(ReplaySpeed::AsFastAsPossible).unwrap();
+    assert_eq!(replayer.len(), 2);
+
+    replayer.run().unwrap();
+    assert_eq!(receive_frames(&mut telemetry), vec![0, 1]);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_replay_stop() {
+    let addr = free_local_address();
+    let _telemetry = F1_2021::blocking_telemetry(addr).unwrap();
+
+    // Would take 10 seconds in real time
+    let mut replayer = Replayer::from_records(sample_records(101), addr).unwrap();
+    let handle = replayer.handle();
+
+    let replay = thread::spawn(move || {
+        replayer.run().unwrap();
+        replayer.position()
+    });
+
+    thread::sleep(Duration::from_millis(150));
+    handle.stop();
+
+    let position = replay.join().unwrap();
+    assert!(position > 0 && position < 101, "{}", position);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_replay_to_async_telemetry() {
+    use tokio_stream::StreamExt;
+
+    let addr = free_local_address();
+    let mut stream = F1_2021::telemetry(addr).unwrap();
+
+    let records = sample_records(3);
+    let expected: Vec<Packet> = records
+        .iter()
+        .map(|record| Packet::parse(&record.datagram).unwrap())
+        .collect();
+
+    let mut replayer = Replayer::from_records(records, addr).unwrap();
+    replayer.set_speed(ReplaySpeed::Scaled(10.0)).unwrap();
+
+    let replay = thread::spawn(move || replayer.run());
+
+    for packet in expected {
+        let received = stream.next().await.unwrap().unwrap();
+        assert_eq!(received, packet);
+    }
+
+    replay.join().unwrap().unwrap();
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use f1_2021_telemetry::packet::{Packet, PacketType};
+
+mod common;
+
+use common::parse_sample;
+
+#[test]
+fn test_json_round_trip() {
+    let samples = [
+        "motion.pkt",
+        "session.pkt",
+        "lap_data.pkt",
+        "event_ssta.pkt",
+        "event_fltp.pkt",
+        "event_pena.pkt",
+        "event_butn.pkt",
+        "participants.pkt",
+        "car_setups.pkt",
+        "car_telemetry.pkt",
+        "car_status.pkt",
+        "final_classification.pkt",
+        "lobby_info.pkt",
+        "car_damage.pkt",
+        "session_history.pkt",
+    ];
+
+    for sample in samples {
+        let packet = parse_sample(sample);
+
+        let json = serde_json::to_string(&packet).unwrap();
+        let decoded: Packet = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, packet, "{}", sample);
+    }
+}
+
+#[test]
+fn test_json_event_format() {
+    let packet = parse_sample("event_fltp.pkt");
+
+    let json = match &packet.data {
+        PacketType::Event(data) => serde_json::to_value(data).unwrap(),
+        _ => panic!("wrong packet type"),
+    };
+
+    assert_eq!(json["event_string_code"], "FastestLap");
+    assert_eq!(json["event_details"]["type"], "FastestLap");
+    assert_eq!(json["event_details"]["vehicle_idx"], 1);
+
+    let packet = parse_sample("event_butn.pkt");
+
+    let json = serde_json::to_value(&packet).unwrap();
+
+    assert_eq!(json["header"]["packet_id"], "Event");
+    assert_eq!(json["data"]["Event"]["event_details"]["type"], "Buttons");
+    assert_eq!(
+        json["data"]["Event"]["event_details"]["button_status"],
+        0x00001001
+    );
+}
+
+#[test]
+fn test_json_motion_format() {
+    let packet = parse_sample("motion.pkt");
+
+    let json = match &packet.data {
+        PacketType::Motion(data) => serde_json::to_value(data.car_motion_data[0]).unwrap(),
+        _ => panic!("wrong packet type"),
+    };
+
+    assert!(json.get("world_positon").is_none());
+    assert_eq!(json["world_position"]["x"], 1.0);
+    assert_eq!(json["world_velocity"]["z"], 30.0);
+}
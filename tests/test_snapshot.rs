@@ -0,0 +1,252 @@
+use f1_2021_telemetry::packet::{Packet, PacketType};
+use f1_2021_telemetry::snapshot::{FrameAggregator, RECENT_FRAMES};
+
+mod common;
+
+use common::read_sample;
+
+fn sample_packet(name: &str, session_uid: u64, frame_identifier: u32) -> Packet {
+    let mut packet = Packet::parse(&read_sample(name)).unwrap();
+    packet.header.session_uid = session_uid;
+    packet.header.frame_identifier = frame_identifier;
+
+    packet
+}
+
+#[test]
+fn test_frame_advance() {
+    let mut aggregator = FrameAggregator::new();
+
+    assert!(aggregator
+        .push(sample_packet("motion.pkt", 1, 10))
+        .is_none());
+    assert!(aggregator
+        .push(sample_packet("car_telemetry.pkt", 1, 10))
+        .is_none());
+    assert!(aggregator
+        .push(sample_packet("lap_data.pkt", 1, 10))
+        .is_none());
+
+    let snapshot = aggregator
+        .push(sample_packet("motion.pkt", 1, 11))
+        .expect("frame 10 should be emitted");
+
+    assert_eq!(snapshot.session_uid, 1);
+    assert_eq!(snapshot.frame_identifier, 10);
+    assert!(snapshot.motion.is_some());
+    assert!(snapshot.car_telemetry.is_some());
+    assert!(snapshot.lap_data.is_some());
+    assert!(snapshot.car_status.is_none());
+    assert!(snapshot.events.is_empty());
+
+    let pending = aggregator.pending().unwrap();
+    assert_eq!(pending.frame_identifier, 11);
+    assert!(pending.car_telemetry.is_none());
+
+    let last = aggregator.flush().unwrap();
+    assert_eq!(last.frame_identifier, 11);
+    assert!(aggregator.flush().is_none());
+}
+
+#[test]
+fn test_session_change() {
+    let mut aggregator = FrameAggregator::new();
+
+    aggregator.push(sample_packet("motion.pkt", 1, 10));
+
+    let snapshot = aggregator
+        .push(sample_packet("motion.pkt", 2, 10))
+        .expect("a new session is a new frame");
+    assert_eq!(snapshot.session_uid, 1);
+
+    // Flashbacks go back to earlier frames
+    let snapshot = aggregator.push(sample_packet("motion.pkt", 2, 5)).unwrap();
+    assert_eq!(snapshot.session_uid, 2);
+    assert_eq!(snapshot.frame_identifier, 10);
+}
+
+#[test]
+fn test_repeated_packet_types() {
+    let mut aggregator = FrameAggregator::new();
+
+    let first = sample_packet("car_status.pkt", 1, 10);
+    let mut second = sample_packet("car_status.pkt", 1, 10);
+    second.header.session_time += 1.0;
+    if let PacketType::CarStatus(data) = &mut second.data {
+        data.car_status_data[0].fuel_in_tank += 1.0;
+    }
+
+    aggregator.push(first);
+    aggregator.push(sample_packet("event_butn.pkt", 1, 10));
+    aggregator.push(sample_packet("event_pena.pkt", 1, 10));
+    aggregator.push(sample_packet("session_history.pkt", 1, 10));
+    aggregator.push(sample_packet("session_history.pkt", 1, 10));
+    aggregator.push(second.clone());
+
+    let snapshot = aggregator.flush().unwrap();
+
+    assert_eq!(snapshot.session_time, second.header.session_time);
+    assert_eq!(
+        snapshot.car_status.map(PacketType::CarStatus),
+        Some(second.data)
+    );
+    assert_eq!(snapshot.events.len(), 2);
+    assert_eq!(snapshot.session_history.len(), 2);
+}
+
+#[test]
+fn test_late_packets() {
+    let mut aggregator = FrameAggregator::new();
+
+    aggregator.push(sample_packet("motion.pkt", 1, 10));
+    assert_eq!(aggregator.flush().unwrap().frame_identifier, 10);
+
+    // Frame 10 was already emitted
+    assert!(aggregator
+        .push(sample_packet("lap_data.pkt", 1, 10))
+        .is_none());
+    assert!(aggregator.pending().is_none());
+
+    aggregator.push(sample_packet("motion.pkt", 1, 11));
+    assert_eq!(
+        aggregator
+            .push(sample_packet("motion.pkt", 1, 12))
+            .unwrap()
+            .frame_identifier,
+        11
+    );
+    assert!(aggregator
+        .push(sample_packet("lap_data.pkt", 1, 11))
+        .is_none());
+    assert_eq!(aggregator.pending().unwrap().frame_identifier, 12);
+
+    assert_eq!(aggregator.late_packets(), 2);
+
+    // The same frame identifier in another session is a new frame
+    assert!(aggregator
+        .push(sample_packet("motion.pkt", 2, 11))
+        .is_some());
+    assert_eq!(aggregator.pending().unwrap().session_uid, 2);
+}
+
+#[test]
+fn test_late_packets_of_earlier_frames() {
+    let mut aggregator = FrameAggregator::new();
+
+    aggregator.push(sample_packet("motion.pkt", 1, 10));
+    aggregator.push(sample_packet("motion.pkt", 1, 11));
+    aggregator.push(sample_packet("motion.pkt", 1, 12));
+
+    // Frame 10 was emitted before frame 11
+    assert!(aggregator
+        .push(sample_packet("lap_data.pkt", 1, 10))
+        .is_none());
+    assert_eq!(aggregator.pending().unwrap().frame_identifier, 12);
+
+    let mut emitted = Vec::new();
+    emitted.extend(aggregator.push(sample_packet("motion.pkt", 1, 13)));
+    emitted.extend(aggregator.flush());
+
+    let frames: Vec<u32> = emitted.iter().map(|s| s.frame_identifier).collect();
+    assert_eq!(frames, vec![12, 13]);
+    assert_eq!(aggregator.late_packets(), 1);
+
+    // Only the last RECENT_FRAMES frames are remembered
+    for frame in 14..14 + RECENT_FRAMES as u32 {
+        aggregator.push(sample_packet("motion.pkt", 1, frame));
+    }
+    aggregator.flush();
+
+    assert!(aggregator
+        .push(sample_packet("motion.pkt", 1, 13))
+        .is_none());
+    assert_eq!(aggregator.pending().unwrap().frame_identifier, 13);
+    assert_eq!(aggregator.late_packets(), 1);
+}
+
+#[cfg(feature = "tokio")]
+mod stream {
+    use std::time::Duration;
+
+    use tokio_stream::StreamExt;
+
+    use f1_2021_telemetry::error::F1Error;
+    use f1_2021_telemetry::snapshot::FrameSnapshots;
+
+    use super::sample_packet;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stream_frame_advance() {
+        let packets = tokio_stream::iter(vec![
+            Ok(sample_packet("motion.pkt", 1, 10)),
+            Ok(sample_packet("car_telemetry.pkt", 1, 10)),
+            Err(F1Error::IncompleteData),
+            Ok(sample_packet("motion.pkt", 1, 11)),
+        ]);
+        let mut snapshots = FrameSnapshots::new(packets, Duration::from_secs(1));
+
+        assert!(matches!(
+            snapshots.next().await,
+            Some(Err(F1Error::IncompleteData))
+        ));
+
+        let snapshot = snapshots.next().await.unwrap().unwrap();
+        assert_eq!(snapshot.frame_identifier, 10);
+        assert!(snapshot.car_telemetry.is_some());
+
+        // The pending frame is emitted when the packets end
+        let snapshot = snapshots.next().await.unwrap().unwrap();
+        assert_eq!(snapshot.frame_identifier, 11);
+
+        assert!(snapshots.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stream_timeout() {
+        let packets = tokio_stream::iter(vec![
+            Ok(sample_packet("motion.pkt", 1, 10)),
+            Ok(sample_packet("lap_data.pkt", 1, 10)),
+        ])
+        .chain(tokio_stream::pending());
+        let mut snapshots = FrameSnapshots::new(packets, Duration::from_millis(50));
+
+        let start = tokio::time::Instant::now();
+        let snapshot = snapshots.next().await.unwrap().unwrap();
+
+        assert_eq!(start.elapsed(), Duration::from_millis(50));
+        assert_eq!(snapshot.frame_identifier, 10);
+        assert!(snapshot.motion.is_some());
+        assert!(snapshot.lap_data.is_some());
+
+        // Nothing is pending, the stream waits for packets
+        let next = tokio::time::timeout(Duration::from_secs(10), snapshots.next()).await;
+        assert!(next.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stream_late_packet_after_timeout() {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let packets = tokio_stream::wrappers::UnboundedReceiverStream::new(receiver);
+        let mut snapshots = FrameSnapshots::new(packets, Duration::from_millis(50));
+
+        sender.send(Ok(sample_packet("motion.pkt", 1, 10))).unwrap();
+
+        let snapshot = snapshots.next().await.unwrap().unwrap();
+        assert_eq!(snapshot.frame_identifier, 10);
+        assert!(snapshot.lap_data.is_none());
+
+        // Arrives after the timeout emitted frame 10
+        sender
+            .send(Ok(sample_packet("lap_data.pkt", 1, 10)))
+            .unwrap();
+        sender.send(Ok(sample_packet("motion.pkt", 1, 11))).unwrap();
+        drop(sender);
+
+        let snapshot = snapshots.next().await.unwrap().unwrap();
+        assert_eq!(snapshot.frame_identifier, 11);
+        assert!(snapshot.lap_data.is_none());
+
+        assert!(snapshots.next().await.is_none());
+        assert_eq!(snapshots.late_packets(), 1);
+    }
+}
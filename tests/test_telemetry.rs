@@ -0,0 +1,102 @@
+#![cfg(feature = "tokio")]
+
+use std::net::UdpSocket;
+use std::time::Duration;
+
+use tokio_stream::StreamExt;
+
+use f1_2021_telemetry::error::F1Error;
+use f1_2021_telemetry::packet;
+use f1_2021_telemetry::F1_2021;
+
+mod common;
+
+use common::{free_local_address, read_sample};
+
+#[tokio::test]
+async fn test_telemetry_skips_bad_datagrams() {
+    let address = free_local_address();
+    let mut stream = Box::pin(F1_2021::telemetry(address).unwrap());
+
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    let mut unknown_event = read_sample("event_ssta.pkt");
+    unknown_event[packet::header::HEADER_SIZE..].copy_from_slice(b"OVTK");
+
+    sender.send_to(&[0xe5, 0x07, 0x01], address).unwrap();
+    sender.send_to(&unknown_event, address).unwrap();
+    sender.send_to(&read_sample("header.pkt"), address).unwrap();
+    sender
+        .send_to(&read_sample("event_ssta.pkt"), address)
+        .unwrap();
+
+    assert!(matches!(
+        stream.next().await,
+        Some(Err(F1Error::IncompleteData))
+    ));
+    assert!(matches!(
+        stream.next().await,
+        Some(Err(F1Error::ConversionError))
+    ));
+    assert!(matches!(
+        stream.next().await,
+        Some(Err(F1Error::IncompleteData))
+    ));
+
+    let packet = stream.next().await.unwrap().unwrap();
+
+    assert_eq!(packet.header.packet_id, packet::header::PacketId::Event);
+}
+
+#[tokio::test]
+async fn test_telemetry_with_source() {
+    let address = free_local_address();
+    let mut stream = Box::pin(F1_2021::telemetry_with_source(address).unwrap());
+
+    let first_rig = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let second_rig = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    first_rig
+        .send_to(&read_sample("event_ssta.pkt"), address)
+        .unwrap();
+    second_rig
+        .send_to(&read_sample("event_butn.pkt"), address)
+        .unwrap();
+
+    let first = stream.next().await.unwrap().unwrap();
+    let second = stream.next().await.unwrap().unwrap();
+
+    assert_eq!(first.source, first_rig.local_addr().unwrap());
+    assert_eq!(second.source, second_rig.local_addr().unwrap());
+    assert!(second.received_at >= first.received_at);
+    assert_eq!(first.datagram, read_sample("event_ssta.pkt"));
+    assert_eq!(second.datagram, read_sample("event_butn.pkt"));
+    assert_eq!(
+        first.packet.data,
+        packet::PacketType::Event(packet::event::EventData {
+            event_string_code: packet::event::EventCode::SessionStarted,
+            event_details: packet::event::EventDataDetails::SessionStarted,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_frame_snapshots() {
+    let address = free_local_address();
+    let mut stream =
+        Box::pin(F1_2021::frame_snapshots(address, Duration::from_millis(100)).unwrap());
+
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    sender.send_to(&read_sample("motion.pkt"), address).unwrap();
+    sender
+        .send_to(&read_sample("car_telemetry.pkt"), address)
+        .unwrap();
+
+    // Both samples share a frame, it's emitted once the timeout passes
+    let snapshot = stream.next().await.unwrap().unwrap();
+
+    assert!(snapshot.motion.is_some());
+    assert!(snapshot.car_telemetry.is_some());
+    assert!(snapshot.lap_data.is_none());
+}
@@ -0,0 +1,139 @@
+use f1_2021_telemetry::error::F1Error;
+use f1_2021_telemetry::packet::car_telemetry::TelemetryView;
+use f1_2021_telemetry::packet::motion::MotionView;
+use f1_2021_telemetry::packet::{Packet, PacketType};
+use f1_2021_telemetry::utils::NUMBER_OF_CARS;
+
+mod common;
+
+use common::{parse_sample, read_sample};
+
+/// The samples repeat the same car, every car gets distinct values to catch offset mistakes
+fn distinct_cars_sample(name: &str) -> Vec<u8> {
+    let mut packet = parse_sample(name);
+
+    match &mut packet.data {
+        PacketType::Motion(data) => {
+            for (i, car) in data.car_motion_data.iter_mut().enumerate() {
+                car.world_positon.x = i as f32;
+                car.world_velocity.y = i as f32 * 2.0;
+                car.world_right_dir.z = i as i16;
+                car.roll = i as f32 * 0.5;
+            }
+        }
+        PacketType::CarTelemetry(data) => {
+            for (i, car) in data.car_telemetry_data.iter_mut().enumerate() {
+                car.speed = i as u16 * 10;
+                car.gear = i as i8 % 9;
+                car.engine_rpm = 1000 + i as u16;
+                car.tyres_pressure.front_right = i as f32;
+            }
+        }
+        _ => panic!("sample has no per car data"),
+    }
+
+    packet.to_bytes().to_vec()
+}
+
+#[test]
+fn test_motion_view() {
+    let raw = read_sample("motion.pkt");
+    let packet = Packet::parse(&raw).unwrap();
+    let view = MotionView::new(&raw).unwrap();
+
+    let data = match packet.data {
+        PacketType::Motion(data) => data,
+        _ => panic!("wrong packet type"),
+    };
+
+    assert_eq!(view.header(), &packet.header);
+    assert_eq!(view.decode(), data);
+
+    for i in 0..NUMBER_OF_CARS {
+        assert_eq!(view.car(i).unwrap().decode(), data.car_motion_data[i]);
+    }
+    assert_eq!(view.cars().count(), NUMBER_OF_CARS);
+    assert!(view.car(NUMBER_OF_CARS).is_none());
+
+    let player = view.player_car().unwrap();
+    let player_data = data.car_motion_data[packet.header.player_car_index as usize];
+    assert_eq!(player.world_position(), player_data.world_positon);
+    assert_eq!(player.roll(), player_data.roll);
+
+    assert_eq!(view.wheel_slip(), data.wheel_slip);
+    assert_eq!(view.angular_acceleration(), data.angular_acceleration);
+    assert_eq!(view.front_wheels_angle(), data.front_wheels_angle);
+}
+
+#[test]
+fn test_telemetry_view() {
+    let raw = read_sample("car_telemetry.pkt");
+    let packet = Packet::parse(&raw).unwrap();
+    let view = TelemetryView::new(&raw).unwrap();
+
+    let data = match packet.data {
+        PacketType::CarTelemetry(data) => data,
+        _ => panic!("wrong packet type"),
+    };
+
+    assert_eq!(view.decode(), data);
+
+    for (car, car_data) in view.cars().zip(data.car_telemetry_data.iter()) {
+        assert_eq!(&car.decode(), car_data);
+    }
+
+    let player = view.player_car().unwrap();
+    let player_data = data.car_telemetry_data[packet.header.player_car_index as usize];
+    assert_eq!(player.speed(), player_data.speed);
+    assert_eq!(player.gear(), player_data.gear);
+    assert_eq!(player.surface_type(), player_data.surface_type);
+
+    assert_eq!(view.mfd_panel_index(), data.mfd_panel_index);
+    assert_eq!(view.suggested_gear(), data.suggested_gear);
+}
+
+#[test]
+fn test_views_per_car_offsets() {
+    let motion = distinct_cars_sample("motion.pkt");
+    let motion_view = MotionView::new(&motion).unwrap();
+
+    let telemetry = distinct_cars_sample("car_telemetry.pkt");
+    let telemetry_view = TelemetryView::new(&telemetry).unwrap();
+
+    let (motion_data, telemetry_data) = match (
+        Packet::parse(&motion).unwrap().data,
+        Packet::parse(&telemetry).unwrap().data,
+    ) {
+        (PacketType::Motion(motion), PacketType::CarTelemetry(telemetry)) => (motion, telemetry),
+        _ => panic!("wrong packet type"),
+    };
+
+    for i in 0..NUMBER_OF_CARS {
+        let car = motion_view.car(i).unwrap();
+        assert_eq!(car.world_position().x, i as f32);
+        assert_eq!(car.roll(), i as f32 * 0.5);
+        assert_eq!(car.decode(), motion_data.car_motion_data[i]);
+
+        let car = telemetry_view.car(i).unwrap();
+        assert_eq!(car.speed(), i as u16 * 10);
+        assert_eq!(car.engine_rpm(), 1000 + i as u16);
+        assert_eq!(car.decode(), telemetry_data.car_telemetry_data[i]);
+    }
+
+    assert_eq!(motion_view.decode(), motion_data);
+    assert_eq!(telemetry_view.decode(), telemetry_data);
+}
+
+#[test]
+fn test_view_errors() {
+    let motion = read_sample("motion.pkt");
+
+    assert!(matches!(
+        TelemetryView::new(&motion),
+        Err(F1Error::ConversionError)
+    ));
+    assert!(matches!(
+        MotionView::new(&motion[..motion.len() - 1]),
+        Err(F1Error::IncompleteData)
+    ));
+}